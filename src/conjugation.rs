@@ -0,0 +1,649 @@
+#[cfg(feature = "wasm-support")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::util::{display_width, escape_html};
+use crate::word_dto::{
+    Conjugation, ConjugationCell, ConjugationRow, ConjugationTable, Government, RenderOptions,
+    WordData, WordForm,
+};
+
+pub(crate) const TENSES: [&str; 4] = ["present", "past", "future", "imperative"];
+pub(crate) const PERSONS: [&str; 4] = ["1st", "2nd", "3rd", "all"];
+pub(crate) const NUMBERS: [&str; 2] = ["singular", "plural"];
+pub(crate) const GENDERS: [&str; 3] = ["m", "f", "all"];
+
+pub(crate) fn position(values: &[&str], value: &str) -> usize {
+    values
+        .iter()
+        .position(|v| *v == value)
+        .unwrap_or(values.len())
+}
+
+fn cell_order(cell: &ConjugationCell) -> (usize, usize, usize) {
+    (
+        position(&PERSONS, &cell.person),
+        position(&NUMBERS, &cell.number),
+        position(&GENDERS, &cell.gender),
+    )
+}
+
+fn same_spelling(a: &WordForm, b: &WordForm) -> bool {
+    a.form == b.form && a.form_vowelled == b.form_vowelled && a.transcription == b.transcription
+}
+
+/// Cells of one tense. Masculine and feminine forms spelled alike are merged into an "all"
+/// cell, and a cell already marked "all" hides gendered duplicates of itself.
+fn row(tense: &str, forms: &[WordForm]) -> Option<ConjugationRow> {
+    let mut cells: Vec<ConjugationCell> = Vec::new();
+    for form in forms.iter().filter(|f| f.tense == tense) {
+        let taken = cells.iter().any(|c| {
+            c.person == form.person
+                && c.number == form.number
+                && (c.gender == form.gender || c.gender == "all" || form.gender == "all")
+        });
+        if !taken {
+            cells.push(ConjugationCell {
+                person: form.person.clone(),
+                number: form.number.clone(),
+                gender: form.gender.clone(),
+                form: form.clone(),
+            });
+        }
+    }
+    let mut merged: Vec<ConjugationCell> = Vec::new();
+    for cell in cells {
+        let twin = merged.iter_mut().find(|c| {
+            c.person == cell.person
+                && c.number == cell.number
+                && c.gender != cell.gender
+                && same_spelling(&c.form, &cell.form)
+        });
+        match twin {
+            Some(twin) => twin.gender = "all".to_string(),
+            None => merged.push(cell),
+        }
+    }
+    if merged.is_empty() {
+        return None;
+    }
+    merged.sort_by_key(cell_order);
+    Some(ConjugationRow {
+        tense: tense.to_string(),
+        cells: merged,
+    })
+}
+
+impl ConjugationTable {
+    pub(crate) fn new(
+        url_id: &str,
+        binyan: &str,
+        infinitive: Option<WordForm>,
+        forms: &[WordForm],
+    ) -> ConjugationTable {
+        ConjugationTable {
+            url_id: url_id.to_string(),
+            binyan: binyan.to_string(),
+            infinitive,
+            rows: TENSES
+                .iter()
+                .filter_map(|tense| row(tense, forms))
+                .collect(),
+        }
+    }
+}
+
+#[cfg_attr(feature = "wasm-support", wasm_bindgen)]
+impl ConjugationTable {
+    pub fn row(&self, tense: &str) -> Option<ConjugationRow> {
+        self.rows.iter().find(|r| r.tense == tense).cloned()
+    }
+
+    /// The cell holding the given form, a cell for "all" persons or genders matches any.
+    pub fn cell(
+        &self,
+        tense: &str,
+        person: &str,
+        number: &str,
+        gender: &str,
+    ) -> Option<ConjugationCell> {
+        let row = self.rows.iter().find(|r| r.tense == tense)?;
+        row.cells
+            .iter()
+            .find(|c| {
+                (c.person == person || c.person == "all")
+                    && c.number == number
+                    && (c.gender == gender || c.gender == "all")
+            })
+            .cloned()
+    }
+}
+
+#[cfg_attr(feature = "wasm-support", wasm_bindgen)]
+impl WordData {
+    /// Conjugation tables of the verb and, when it has one, of its passive binyan, along with
+    /// the prepositions it governs.
+    pub fn conjugation(&self) -> Conjugation {
+        let active = ConjugationTable::new(
+            &self.url_id,
+            &self.binyan,
+            Some(self.infinitive_form()),
+            &self.forms,
+        );
+        let passive = match (&self.passive, &self.passive_binyan) {
+            (Some(forms), Some(binyan)) => {
+                Some(ConjugationTable::new(&self.url_id, binyan, None, forms))
+            }
+            _ => None,
+        };
+        Conjugation {
+            active,
+            passive,
+            government: self.government.clone(),
+        }
+    }
+}
+
+const COLUMNS: [&str; 4] = ["m. sg.", "f. sg.", "m. pl.", "f. pl."];
+
+/// A cell of the rendered grid spanning `span` columns, `None` for an empty one.
+struct GridCell<'a> {
+    cell: Option<&'a ConjugationCell>,
+    span: usize,
+}
+
+/// The table as rendered: one line per tense and person, columns for masculine and feminine
+/// singular and plural. Forms shared by both genders span two columns.
+fn grid(table: &ConjugationTable) -> Vec<(String, Vec<GridCell<'_>>)> {
+    let mut lines = Vec::new();
+    for row in &table.rows {
+        let mut persons: Vec<&str> = Vec::new();
+        for cell in &row.cells {
+            if !persons.contains(&cell.person.as_str()) {
+                persons.push(&cell.person);
+            }
+        }
+        for person in persons {
+            let find = |number: &str, gender: &str| {
+                row.cells
+                    .iter()
+                    .find(|c| c.person == person && c.number == number && c.gender == gender)
+            };
+            let mut cells = Vec::new();
+            for number in NUMBERS {
+                match find(number, "all") {
+                    Some(cell) => cells.push(GridCell {
+                        cell: Some(cell),
+                        span: 2,
+                    }),
+                    None => {
+                        for gender in ["m", "f"] {
+                            cells.push(GridCell {
+                                cell: find(number, gender),
+                                span: 1,
+                            });
+                        }
+                    }
+                }
+            }
+            let mut label = capitalize(&row.tense);
+            if person != "all" {
+                label = format!("{} {}", label, person);
+            }
+            lines.push((label, cells));
+        }
+    }
+    lines
+}
+
+/// A grid line as text: its label and, per cell, the columns spanned and the cell's lines.
+type TextRow = (String, Vec<(usize, Vec<String>)>);
+
+fn capitalize(value: &str) -> String {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn spelling<'a>(form: &'a WordForm, options: &RenderOptions) -> &'a str {
+    match &form.form_vowelled {
+        Some(vowelled) if options.vowelled => vowelled,
+        _ => &form.form,
+    }
+}
+
+/// Lines of a cell: the spelling, the transcription and, if asked for, the meaning.
+fn cell_lines(form: &WordForm, options: &RenderOptions) -> Vec<String> {
+    let mut lines = vec![
+        spelling(form, options).to_string(),
+        form.transcription.clone(),
+    ];
+    if options.meaning {
+        lines.push(form.meaning.clone());
+    }
+    lines
+}
+
+fn title(table: &ConjugationTable, options: &RenderOptions) -> String {
+    match &table.infinitive {
+        Some(infinitive) => format!(
+            "{} · {} ({})",
+            table.binyan,
+            spelling(infinitive, options),
+            infinitive.transcription
+        ),
+        None => table.binyan.clone(),
+    }
+}
+
+fn escape_markdown(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+#[cfg_attr(feature = "wasm-support", wasm_bindgen)]
+impl ConjugationTable {
+    /// GitHub Markdown table under a heading line with the binyan and infinitive, lines of
+    /// a cell are joined by `<br>` and forms shared by both genders are repeated in each
+    /// gender column.
+    pub fn to_markdown(&self, options: &RenderOptions) -> String {
+        let mut lines = vec![
+            format!("**{}**", escape_markdown(&title(self, options))),
+            String::new(),
+            format!("| | {} |", COLUMNS.join(" | ")),
+            format!("|---|{}", "---|".repeat(COLUMNS.len())),
+        ];
+        for (label, cells) in grid(self) {
+            let mut line = format!("| {} |", label);
+            for grid_cell in cells {
+                let value = grid_cell.cell.map_or(String::new(), |c| {
+                    let mut values = cell_lines(&c.form, options);
+                    if options.meaning {
+                        let meaning = values.pop().unwrap();
+                        values.push(format!("*{}*", meaning));
+                    }
+                    escape_markdown(&values.join("<br>"))
+                });
+                for _ in 0..grid_cell.span {
+                    line.push_str(&format!(" {} |", value));
+                }
+            }
+            lines.push(line);
+        }
+        lines.join("\n")
+    }
+
+    /// Right-to-left HTML table with row and column headers. The transcription is ruby text
+    /// over the form or a second line under it; both spellings are present when they differ,
+    /// the one not asked for carries the `hidden` attribute.
+    pub fn to_html(&self, options: &RenderOptions) -> String {
+        let mut html = String::from("<table class=\"conjugation\" dir=\"rtl\" lang=\"he\">");
+        html.push_str(&format!(
+            "<caption>{}</caption><thead><tr><td></td>",
+            escape_html(&title(self, options))
+        ));
+        for column in COLUMNS {
+            html.push_str(&format!(
+                "<th scope=\"col\" dir=\"ltr\" lang=\"en\">{}</th>",
+                column
+            ));
+        }
+        html.push_str("</tr></thead><tbody>");
+        for (label, cells) in grid(self) {
+            html.push_str(&format!(
+                "<tr><th scope=\"row\" dir=\"ltr\" lang=\"en\">{}</th>",
+                escape_html(&label)
+            ));
+            for grid_cell in cells {
+                if grid_cell.span > 1 {
+                    html.push_str(&format!("<td colspan=\"{}\">", grid_cell.span));
+                } else {
+                    html.push_str("<td>");
+                }
+                if let Some(cell) = grid_cell.cell {
+                    html.push_str(&html_cell(&cell.form, options));
+                }
+                html.push_str("</td>");
+            }
+            html.push_str("</tr>");
+        }
+        html.push_str("</tbody></table>");
+        html
+    }
+
+    /// Plain text for terminals: a title line, then the columns padded to their widest line,
+    /// every form taking two or three lines.
+    pub fn to_text(&self, options: &RenderOptions) -> String {
+        let rows: Vec<TextRow> = grid(self)
+            .into_iter()
+            .map(|(label, cells)| {
+                let cells = cells
+                    .iter()
+                    .map(|g| {
+                        let lines = g.cell.map_or(vec![], |c| cell_lines(&c.form, options));
+                        (g.span, lines)
+                    })
+                    .collect();
+                (label, cells)
+            })
+            .collect();
+        let text_width = |lines: &[String]| lines.iter().map(|l| display_width(l)).max();
+        let label_width = rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+        let mut widths: Vec<usize> = COLUMNS.iter().map(|c| c.len()).collect();
+        for span in [1, 2] {
+            for (_, cells) in &rows {
+                let mut column = 0;
+                for (cell_span, lines) in cells {
+                    let width = text_width(lines).unwrap_or(0);
+                    if *cell_span == span {
+                        let available: usize =
+                            widths[column..column + span].iter().sum::<usize>() + 2 * (span - 1);
+                        if width > available {
+                            widths[column + span - 1] += width - available;
+                        }
+                    }
+                    column += cell_span;
+                }
+            }
+        }
+        let pad = |value: &str, width: usize| {
+            format!("{}{}", value, " ".repeat(width - display_width(value)))
+        };
+        let mut lines = vec![title(self, options)];
+        let mut header = pad("", label_width);
+        for (column, width) in COLUMNS.iter().zip(&widths) {
+            header.push_str("  ");
+            header.push_str(&pad(column, *width));
+        }
+        lines.push(header.trim_end().to_string());
+        let line_count = if options.meaning { 3 } else { 2 };
+        for (label, cells) in rows {
+            for i in 0..line_count {
+                let mut line = pad(if i == 0 { &label } else { "" }, label_width);
+                let mut column = 0;
+                for (span, cell_lines) in &cells {
+                    let width =
+                        widths[column..column + span].iter().sum::<usize>() + 2 * (span - 1);
+                    line.push_str("  ");
+                    line.push_str(&pad(cell_lines.get(i).map_or("", |l| l.as_str()), width));
+                    column += span;
+                }
+                lines.push(line.trim_end().to_string());
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+fn html_cell(form: &WordForm, options: &RenderOptions) -> String {
+    let mut spellings = vec![(
+        "conjugation-form",
+        form.form_vowelled.as_deref().unwrap_or(&form.form),
+        options.vowelled,
+    )];
+    if let Some(vowelled) = &form.form_vowelled {
+        if *vowelled != form.form {
+            spellings[0].0 = "conjugation-vowelled";
+            spellings.push(("conjugation-plain", &form.form, !options.vowelled));
+        }
+    }
+    if spellings.len() == 1 {
+        spellings[0].2 = true;
+    }
+    let mut html = String::new();
+    for (class, spelling, shown) in spellings {
+        let hidden = if shown { "" } else { " hidden" };
+        let spelling = escape_html(spelling);
+        let transcription = escape_html(&form.transcription);
+        if options.ruby {
+            html.push_str(&format!(
+                "<ruby class=\"{}\"{}>{}<rt dir=\"ltr\" lang=\"he-Latn\">{}</rt></ruby>",
+                class, hidden, spelling, transcription
+            ));
+        } else {
+            html.push_str(&format!(
+                "<span class=\"{}\"{}>{}<br><span class=\"conjugation-transcription\" \
+                 dir=\"ltr\" lang=\"he-Latn\">{}</span></span>",
+                class, hidden, spelling, transcription
+            ));
+        }
+    }
+    if options.meaning {
+        html.push_str(&format!(
+            "<br><span class=\"conjugation-meaning\" dir=\"ltr\" lang=\"en\">{}</span>",
+            escape_html(&form.meaning)
+        ));
+    }
+    html
+}
+
+#[cfg_attr(feature = "wasm-support", wasm_bindgen)]
+impl Conjugation {
+    /// The active table followed by the passive one, if any, and a list of the prepositions
+    /// the verb governs.
+    pub fn to_markdown(&self, options: &RenderOptions) -> String {
+        let mut parts: Vec<String> = self
+            .tables()
+            .iter()
+            .map(|t| t.to_markdown(options))
+            .collect();
+        if !self.government.is_empty() {
+            let mut lines = vec!["**Government**".to_string(), String::new()];
+            for government in &self.government {
+                lines.push(format!(
+                    "- {}",
+                    escape_markdown(&government_line(government))
+                ));
+            }
+            parts.push(lines.join("\n"));
+        }
+        parts.join("\n\n")
+    }
+
+    /// The tables, then the government as a definition list of the examples of each
+    /// preposition.
+    pub fn to_html(&self, options: &RenderOptions) -> String {
+        let mut parts: Vec<String> = self.tables().iter().map(|t| t.to_html(options)).collect();
+        if !self.government.is_empty() {
+            let mut html = String::from("<dl class=\"government\">");
+            for government in &self.government {
+                html.push_str(&format!(
+                    "<dt dir=\"ltr\" lang=\"en\"><span lang=\"he\">{}</span> + {}</dt>",
+                    escape_html(&government.preposition),
+                    escape_html(&government.argument)
+                ));
+                for example in &government.examples {
+                    html.push_str(&format!(
+                        "<dd dir=\"rtl\" lang=\"he\">{}</dd>",
+                        escape_html(example)
+                    ));
+                }
+            }
+            html.push_str("</dl>");
+            parts.push(html);
+        }
+        parts.join("\n")
+    }
+
+    pub fn to_text(&self, options: &RenderOptions) -> String {
+        let mut parts: Vec<String> = self.tables().iter().map(|t| t.to_text(options)).collect();
+        if !self.government.is_empty() {
+            let mut lines = vec!["Government".to_string()];
+            lines.extend(self.government.iter().map(government_line));
+            parts.push(lines.join("\n"));
+        }
+        parts.join("\n\n")
+    }
+}
+
+/// "ל + person: לכתוב לחבר; ..." for the Markdown and text renderings.
+fn government_line(government: &Government) -> String {
+    let mut line = format!("{} + {}", government.preposition, government.argument);
+    if !government.examples.is_empty() {
+        line.push_str(&format!(": {}", government.examples.join("; ")));
+    }
+    line
+}
+
+impl Conjugation {
+    fn tables(&self) -> Vec<&ConjugationTable> {
+        let mut tables = vec![&self.active];
+        tables.extend(&self.passive);
+        tables
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_data::{lesader, lichtov};
+    use crate::word_dto::RenderOptions;
+
+    #[test]
+    fn conjugation_table() {
+        let conjugation = lichtov().conjugation();
+        let table = &conjugation.active;
+        let tenses: Vec<&str> = table.rows.iter().map(|r| r.tense.as_str()).collect();
+        assert_eq!(tenses, vec!["present", "past", "future", "imperative"]);
+        assert_eq!(table.infinitive.as_ref().unwrap().form, "לכתוב");
+        assert!(conjugation.passive.is_none());
+
+        let past = table.row("past").unwrap();
+        let keys: Vec<String> = past
+            .cells
+            .iter()
+            .map(|c| format!("{}.{}.{}", c.person, c.number, c.gender))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "1st.singular.all",
+                "1st.plural.all",
+                "2nd.singular.m",
+                "2nd.singular.f",
+                "2nd.plural.m",
+                "2nd.plural.f",
+                "3rd.singular.m",
+                "3rd.singular.f",
+                "3rd.plural.all",
+            ]
+        );
+        let cell = table.cell("past", "1st", "singular", "f").unwrap();
+        assert_eq!(cell.form.form, "כתבתי");
+        let cell = table.cell("present", "2nd", "plural", "f").unwrap();
+        assert_eq!(cell.form.form, "כותבות");
+        assert!(table.cell("imperative", "1st", "singular", "m").is_none());
+    }
+
+    #[test]
+    fn passive_table_and_merged_genders() {
+        let mut word = lesader();
+        let conjugation = word.conjugation();
+        let passive = conjugation.passive.unwrap();
+        assert_eq!(passive.binyan, "PU'AL");
+        assert!(passive.infinitive.is_none());
+        assert!(passive.row("imperative").is_none());
+        assert_eq!(passive.row("past").unwrap().cells.len(), 9);
+
+        // a feminine twin of a masculine form is folded into one cell
+        let mut twin = word.forms[0].clone();
+        twin.gender = "f".to_string();
+        word.forms
+            .retain(|f| !(f.tense == "present" && f.number == "singular" && f.gender == "f"));
+        word.forms.push(twin);
+        let table = word.conjugation().active;
+        let present = table.row("present").unwrap();
+        assert_eq!(present.cells.len(), 3);
+        assert_eq!(present.cells[0].gender, "all");
+        let json = serde_json::to_string(&table).unwrap();
+        assert!(json.contains("\"tense\":\"present\""));
+    }
+
+    #[test]
+    fn render_markdown_and_html() {
+        let conjugation = lichtov().conjugation();
+        let options = RenderOptions::default();
+        let markdown = conjugation.active.to_markdown(&options);
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(lines[0], "**PA'AL · לכתוב (lichtov)**");
+        assert_eq!(lines[2], "| | m. sg. | f. sg. | m. pl. | f. pl. |");
+        assert_eq!(
+            lines[5],
+            "| Past 1st | כָּתַבְתִּי<br>katavti | כָּתַבְתִּי<br>katavti \
+             | כָּתַבְנוּ<br>katavnu | כָּתַבְנוּ<br>katavnu |"
+        );
+        assert_eq!(lines.len(), 4 + 1 + 3 + 3 + 1);
+        let with_meaning = RenderOptions::new(false, false, true);
+        assert!(conjugation
+            .to_markdown(&with_meaning)
+            .contains("| Imperative 2nd | כתוב<br>ktov<br>*write!* |"));
+
+        let html = conjugation.active.to_html(&options);
+        assert!(html.starts_with("<table class=\"conjugation\" dir=\"rtl\" lang=\"he\">"));
+        assert!(html.contains(
+            "<td colspan=\"2\"><ruby class=\"conjugation-vowelled\">כָּתַבְתִּי\
+             <rt dir=\"ltr\" lang=\"he-Latn\">katavti</rt></ruby>\
+             <ruby class=\"conjugation-plain\" hidden>כתבתי\
+             <rt dir=\"ltr\" lang=\"he-Latn\">katavti</rt></ruby></td>"
+        ));
+        assert_eq!(html.matches("<th scope=\"row\"").count(), 8);
+        let lines_html = conjugation
+            .active
+            .to_html(&RenderOptions::new(false, false, false));
+        assert!(lines_html.contains("<span class=\"conjugation-vowelled\" hidden>"));
+        assert!(lines_html.contains("<span class=\"conjugation-transcription\""));
+    }
+
+    #[test]
+    fn render_government() {
+        let conjugation = lichtov().conjugation();
+        let options = RenderOptions::default();
+        let markdown = conjugation.to_markdown(&options);
+        assert!(markdown
+            .ends_with("**Government**\n\n- ל + person: לכתוב לחבר\n- את + thing: לכתוב את המכתב"));
+        let html = conjugation.to_html(&options);
+        assert!(html.ends_with(
+            "<dl class=\"government\"><dt dir=\"ltr\" lang=\"en\"><span lang=\"he\">ל</span> \
+             + person</dt><dd dir=\"rtl\" lang=\"he\">לכתוב לחבר</dd>\
+             <dt dir=\"ltr\" lang=\"en\"><span lang=\"he\">את</span> + thing</dt>\
+             <dd dir=\"rtl\" lang=\"he\">לכתוב את המכתב</dd></dl>"
+        ));
+        let text = conjugation.to_text(&options);
+        assert!(
+            text.ends_with("\n\nGovernment\nל + person: לכתוב לחבר\nאת + thing: לכתוב את המכתב")
+        );
+        let mut word = lichtov();
+        word.government.clear();
+        assert!(!word
+            .conjugation()
+            .to_markdown(&options)
+            .contains("Government"));
+    }
+
+    #[test]
+    fn render_text() {
+        let table = lesader().conjugation().passive.unwrap();
+        let text = table.to_text(&RenderOptions::new(false, true, false));
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "PU'AL");
+        assert_eq!(
+            lines[1],
+            format!(
+                "{:10}  {:7}  {:9}  {:9}  f. pl.",
+                "", "m. sg.", "f. sg.", "m. pl."
+            )
+        );
+        assert_eq!(
+            lines[2],
+            format!(
+                "{:10}  {:7}  {:9}  {:9}  {}",
+                "Present", "מסודר", "מסודרת", "מסודרים", "מסודרות"
+            )
+        );
+        // forms shared by both genders span the two gender columns
+        assert_eq!(
+            lines[4],
+            format!("{:10}  {:18}  {}", "Past 1st", "סודרתי", "סודרנו")
+        );
+        assert_eq!(lines.len(), 2 + 7 * 2);
+    }
+}
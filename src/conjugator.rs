@@ -0,0 +1,680 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+#[cfg(feature = "wasm-support")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::gizra::{classify_root, AYIN_GUTTURAL, SHLEMIM};
+use crate::infinitive::infinitive_forms;
+use crate::query::canonical_binyan;
+use crate::util::{is_hebrew_letter, normalize_root, unfinal};
+use crate::word_dto::{WordData, WordForm};
+
+/// Error returned by `WordData::conjugate` for unknown binyanim and unsupported roots.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ConjugateError {
+    pub message: String,
+}
+
+impl fmt::Display for ConjugateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ConjugateError {}
+
+fn error(message: String) -> ConjugateError {
+    ConjugateError { message }
+}
+
+pub(crate) const BINYANIM: [&str; 7] = [
+    "PA'AL", "NIF'AL", "PI'EL", "PU'AL", "HIF'IL", "HUF'AL", "HITPA'EL",
+];
+
+/// Tense, person, number and gender of every cell, in dataset order. Passive binyanim stop
+/// before the imperative.
+#[rustfmt::skip]
+const CELLS: [(&str, &str, &str, &str); 27] = [
+    ("present", "all", "singular", "m"), ("present", "all", "singular", "f"),
+    ("present", "all", "plural", "m"), ("present", "all", "plural", "f"),
+    ("past", "1st", "singular", "all"), ("past", "1st", "plural", "all"),
+    ("past", "2nd", "singular", "m"), ("past", "2nd", "singular", "f"),
+    ("past", "2nd", "plural", "m"), ("past", "2nd", "plural", "f"),
+    ("past", "3rd", "singular", "m"), ("past", "3rd", "singular", "f"),
+    ("past", "3rd", "plural", "all"),
+    ("future", "1st", "singular", "all"), ("future", "1st", "plural", "all"),
+    ("future", "2nd", "singular", "m"), ("future", "2nd", "singular", "f"),
+    ("future", "2nd", "plural", "m"), ("future", "2nd", "plural", "f"),
+    ("future", "3rd", "singular", "m"), ("future", "3rd", "singular", "f"),
+    ("future", "3rd", "plural", "m"), ("future", "3rd", "plural", "f"),
+    ("imperative", "2nd", "singular", "m"), ("imperative", "2nd", "singular", "f"),
+    ("imperative", "2nd", "plural", "m"), ("imperative", "2nd", "plural", "f"),
+];
+
+/// Templates of a binyan for regular roots. Digits are root letters; in the vowelled and
+/// transcription templates a root letter followed by `+` takes a dagesh kal (hard ב, כ, פ)
+/// and one followed by `=` a dagesh forte.
+struct Paradigm {
+    /// Infinitive as (vowelled, ktiv male, transcription).
+    infinitive: Option<(&'static str, &'static str, &'static str)>,
+    /// (vowelled, ktiv male, transcription) for each of `CELLS`.
+    forms: &'static [(&'static str, &'static str, &'static str)],
+}
+
+#[rustfmt::skip]
+const PAAL: Paradigm = Paradigm {
+    infinitive: Some(("לִ1ְ2+ֹ3", "ל12ו3", "li12+o3")),
+    forms: &[
+        ("1+וֹ2ֵ3", "1ו23", "1+o2e3"), ("1+וֹ2ֶ3ֶת", "1ו23ת", "1+o2e3et"),
+        ("1+וֹ2ְ3ִים", "1ו23ים", "1+o23im"), ("1+וֹ2ְ3וֹת", "1ו23ות", "1+o23ot"),
+        ("1+ָ2ַ3ְתִּי", "123תי", "1+a2a3ti"), ("1+ָ2ַ3ְנוּ", "123נו", "1+a2a3nu"),
+        ("1+ָ2ַ3ְתָּ", "123ת", "1+a2a3ta"), ("1+ָ2ַ3ְתְּ", "123ת", "1+a2a3t"),
+        ("1+ְ2ַ3ְתֶּם", "123תם", "1+2a3tem"), ("1+ְ2ַ3ְתֶּן", "123תן", "1+2a3ten"),
+        ("1+ָ2ַ3", "123", "1+a2a3"), ("1+ָ2ְ3ָה", "123ה", "1+a23a"),
+        ("1+ָ2ְ3וּ", "123ו", "1+a23u"),
+        ("אֶ1ְ2+ֹ3", "א12ו3", "e12+o3"), ("נִ1ְ2+ֹ3", "נ12ו3", "ni12+o3"),
+        ("תִּ1ְ2+ֹ3", "ת12ו3", "ti12+o3"), ("תִּ1ְ2+ְ3ִי", "ת123י", "ti12+e3i"),
+        ("תִּ1ְ2+ְ3וּ", "ת123ו", "ti12+e3u"), ("תִּ1ְ2+ֹ3ְנָה", "ת12ו3נה", "ti12+o3na"),
+        ("יִ1ְ2+ֹ3", "י12ו3", "yi12+o3"), ("תִּ1ְ2+ֹ3", "ת12ו3", "ti12+o3"),
+        ("יִ1ְ2+ְ3וּ", "י123ו", "yi12+e3u"), ("תִּ1ְ2+ֹ3ְנָה", "ת12ו3נה", "ti12+o3na"),
+        ("1+ְ2ֹ3", "12ו3", "1+2o3"), ("1+ִ2ְ3ִי", "123י", "1+i23i"),
+        ("1+ִ2ְ3וּ", "123ו", "1+i23u"), ("1+ְ2ֹ3ְנָה", "12ו3נה", "1+2o3na"),
+    ],
+};
+
+#[rustfmt::skip]
+const NIFAL: Paradigm = Paradigm {
+    infinitive: Some(("לְהִ1=ָ2ֵ3", "להי123", "lehi1=a2e3")),
+    forms: &[
+        ("נִ1ְ2+ָ3", "נ123", "ni12+a3"), ("נִ1ְ2+ֶ3ֶת", "נ123ת", "ni12+e3et"),
+        ("נִ1ְ2+ָ3ִים", "נ123ים", "ni12+a3im"), ("נִ1ְ2+ָ3וֹת", "נ123ות", "ni12+a3ot"),
+        ("נִ1ְ2+ַ3ְתִּי", "נ123תי", "ni12+a3ti"), ("נִ1ְ2+ַ3ְנוּ", "נ123נו", "ni12+a3nu"),
+        ("נִ1ְ2+ַ3ְתָּ", "נ123ת", "ni12+a3ta"), ("נִ1ְ2+ַ3ְתְּ", "נ123ת", "ni12+a3t"),
+        ("נִ1ְ2+ַ3ְתֶּם", "נ123תם", "ni12+a3tem"), ("נִ1ְ2+ַ3ְתֶּן", "נ123תן", "ni12+a3ten"),
+        ("נִ1ְ2+ַ3", "נ123", "ni12+a3"), ("נִ1ְ2+ְ3ָה", "נ123ה", "ni12+e3a"),
+        ("נִ1ְ2+ְ3וּ", "נ123ו", "ni12+e3u"),
+        ("אֶ1=ָ2ֵ3", "א123", "e1=a2e3"), ("נִ1=ָ2ֵ3", "ני123", "ni1=a2e3"),
+        ("תִּ1=ָ2ֵ3", "תי123", "ti1=a2e3"), ("תִּ1=ָ2ְ3ִי", "תי123י", "ti1=a23i"),
+        ("תִּ1=ָ2ְ3וּ", "תי123ו", "ti1=a23u"), ("תִּ1=ָ2ַ3ְנָה", "תי123נה", "ti1=a2a3na"),
+        ("יִ1=ָ2ֵ3", "יי123", "yi1=a2e3"), ("תִּ1=ָ2ֵ3", "תי123", "ti1=a2e3"),
+        ("יִ1=ָ2ְ3וּ", "יי123ו", "yi1=a23u"), ("תִּ1=ָ2ַ3ְנָה", "תי123נה", "ti1=a2a3na"),
+        ("הִ1=ָ2ֵ3", "הי123", "hi1=a2e3"), ("הִ1=ָ2ְ3ִי", "הי123י", "hi1=a23i"),
+        ("הִ1=ָ2ְ3וּ", "הי123ו", "hi1=a23u"), ("הִ1=ָ2ַ3ְנָה", "הי123נה", "hi1=a2a3na"),
+    ],
+};
+
+#[rustfmt::skip]
+const PIEL: Paradigm = Paradigm {
+    infinitive: Some(("לְ1ַ2=ֵ3", "ל123", "le1a2=e3")),
+    forms: &[
+        ("מְ1ַ2=ֵ3", "מ123", "me1a2=e3"), ("מְ1ַ2=ֶ3ֶת", "מ123ת", "me1a2=e3et"),
+        ("מְ1ַ2=ְ3ִים", "מ123ים", "me1a2=3im"), ("מְ1ַ2=ְ3וֹת", "מ123ות", "me1a2=3ot"),
+        ("1+ִ2=ַ3ְתִּי", "1י23תי", "1+i2=a3ti"), ("1+ִ2=ַ3ְנוּ", "1י23נו", "1+i2=a3nu"),
+        ("1+ִ2=ַ3ְתָּ", "1י23ת", "1+i2=a3ta"), ("1+ִ2=ַ3ְתְּ", "1י23ת", "1+i2=a3t"),
+        ("1+ִ2=ַ3ְתֶּם", "1י23תם", "1+i2=a3tem"), ("1+ִ2=ַ3ְתֶּן", "1י23תן", "1+i2=a3ten"),
+        ("1+ִ2=ֵ3", "1י23", "1+i2=e3"), ("1+ִ2=ְ3ָה", "1י23ה", "1+i2=3a"),
+        ("1+ִ2=ְ3וּ", "1י23ו", "1+i2=3u"),
+        ("אֲ1ַ2=ֵ3", "א123", "a1a2=e3"), ("נְ1ַ2=ֵ3", "נ123", "ne1a2=e3"),
+        ("תְּ1ַ2=ֵ3", "ת123", "te1a2=e3"), ("תְּ1ַ2=ְ3ִי", "ת123י", "te1a2=3i"),
+        ("תְּ1ַ2=ְ3וּ", "ת123ו", "te1a2=3u"), ("תְּ1ַ2=ֵ3ְנָה", "ת123נה", "te1a2=e3na"),
+        ("יְ1ַ2=ֵ3", "י123", "ye1a2=e3"), ("תְּ1ַ2=ֵ3", "ת123", "te1a2=e3"),
+        ("יְ1ַ2=ְ3וּ", "י123ו", "ye1a2=3u"), ("תְּ1ַ2=ֵ3ְנָה", "ת123נה", "te1a2=e3na"),
+        ("1+ַ2=ֵ3", "123", "1+a2=e3"), ("1+ַ2=ְ3ִי", "123י", "1+a2=3i"),
+        ("1+ַ2=ְ3וּ", "123ו", "1+a2=3u"), ("1+ַ2=ֵ3ְנָה", "123נה", "1+a2=e3na"),
+    ],
+};
+
+#[rustfmt::skip]
+const PUAL: Paradigm = Paradigm {
+    infinitive: None,
+    forms: &[
+        ("מְ1ֻ2=ָ3", "מ1ו23", "me1u2=a3"), ("מְ1ֻ2=ֶ3ֶת", "מ1ו23ת", "me1u2=e3et"),
+        ("מְ1ֻ2=ָ3ִים", "מ1ו23ים", "me1u2=a3im"), ("מְ1ֻ2=ָ3וֹת", "מ1ו23ות", "me1u2=a3ot"),
+        ("1+ֻ2=ַ3ְתִּי", "1ו23תי", "1+u2=a3ti"), ("1+ֻ2=ַ3ְנוּ", "1ו23נו", "1+u2=a3nu"),
+        ("1+ֻ2=ַ3ְתָּ", "1ו23ת", "1+u2=a3ta"), ("1+ֻ2=ַ3ְתְּ", "1ו23ת", "1+u2=a3t"),
+        ("1+ֻ2=ַ3ְתֶּם", "1ו23תם", "1+u2=a3tem"), ("1+ֻ2=ַ3ְתֶּן", "1ו23תן", "1+u2=a3ten"),
+        ("1+ֻ2=ַ3", "1ו23", "1+u2=a3"), ("1+ֻ2=ְ3ָה", "1ו23ה", "1+u2=3a"),
+        ("1+ֻ2=ְ3וּ", "1ו23ו", "1+u2=3u"),
+        ("אֲ1ֻ2=ַ3", "א1ו23", "a1u2=a3"), ("נְ1ֻ2=ַ3", "נ1ו23", "ne1u2=a3"),
+        ("תְּ1ֻ2=ַ3", "ת1ו23", "te1u2=a3"), ("תְּ1ֻ2=ְ3ִי", "ת1ו23י", "te1u2=3i"),
+        ("תְּ1ֻ2=ְ3וּ", "ת1ו23ו", "te1u2=3u"), ("תְּ1ֻ2=ַ3ְנָה", "ת1ו23נה", "te1u2=a3na"),
+        ("יְ1ֻ2=ַ3", "י1ו23", "ye1u2=a3"), ("תְּ1ֻ2=ַ3", "ת1ו23", "te1u2=a3"),
+        ("יְ1ֻ2=ְ3וּ", "י1ו23ו", "ye1u2=3u"), ("תְּ1ֻ2=ַ3ְנָה", "ת1ו23נה", "te1u2=a3na"),
+    ],
+};
+
+#[rustfmt::skip]
+const HIFIL: Paradigm = Paradigm {
+    infinitive: Some(("לְהַ1ְ2+ִי3", "לה12י3", "leha12+i3")),
+    forms: &[
+        ("מַ1ְ2+ִי3", "מ12י3", "ma12+i3"), ("מַ1ְ2+ִי3ָה", "מ12י3ה", "ma12+i3a"),
+        ("מַ1ְ2+ִי3ִים", "מ12י3ים", "ma12+i3im"), ("מַ1ְ2+ִי3וֹת", "מ12י3ות", "ma12+i3ot"),
+        ("הִ1ְ2+ַ3ְתִּי", "ה123תי", "hi12+a3ti"), ("הִ1ְ2+ַ3ְנוּ", "ה123נו", "hi12+a3nu"),
+        ("הִ1ְ2+ַ3ְתָּ", "ה123ת", "hi12+a3ta"), ("הִ1ְ2+ַ3ְתְּ", "ה123ת", "hi12+a3t"),
+        ("הִ1ְ2+ַ3ְתֶּם", "ה123תם", "hi12+a3tem"), ("הִ1ְ2+ַ3ְתֶּן", "ה123תן", "hi12+a3ten"),
+        ("הִ1ְ2+ִי3", "ה12י3", "hi12+i3"), ("הִ1ְ2+ִי3ָה", "ה12י3ה", "hi12+i3a"),
+        ("הִ1ְ2+ִי3וּ", "ה12י3ו", "hi12+i3u"),
+        ("אַ1ְ2+ִי3", "א12י3", "a12+i3"), ("נַ1ְ2+ִי3", "נ12י3", "na12+i3"),
+        ("תַּ1ְ2+ִי3", "ת12י3", "ta12+i3"), ("תַּ1ְ2+ִי3ִי", "ת12י3י", "ta12+i3i"),
+        ("תַּ1ְ2+ִי3וּ", "ת12י3ו", "ta12+i3u"), ("תַּ1ְ2+ֵ3ְנָה", "ת123נה", "ta12+e3na"),
+        ("יַ1ְ2+ִי3", "י12י3", "ya12+i3"), ("תַּ1ְ2+ִי3", "ת12י3", "ta12+i3"),
+        ("יַ1ְ2+ִי3וּ", "י12י3ו", "ya12+i3u"), ("תַּ1ְ2+ֵ3ְנָה", "ת123נה", "ta12+e3na"),
+        ("הַ1ְ2+ֵ3", "ה123", "ha12+e3"), ("הַ1ְ2+ִי3ִי", "ה12י3י", "ha12+i3i"),
+        ("הַ1ְ2+ִי3וּ", "ה12י3ו", "ha12+i3u"), ("הַ1ְ2+ֵ3ְנָה", "ה123נה", "ha12+e3na"),
+    ],
+};
+
+#[rustfmt::skip]
+const HUFAL: Paradigm = Paradigm {
+    infinitive: None,
+    forms: &[
+        ("מֻ1ְ2+ָ3", "מו123", "mu12+a3"), ("מֻ1ְ2+ֶ3ֶת", "מו123ת", "mu12+e3et"),
+        ("מֻ1ְ2+ָ3ִים", "מו123ים", "mu12+a3im"), ("מֻ1ְ2+ָ3וֹת", "מו123ות", "mu12+a3ot"),
+        ("הֻ1ְ2+ַ3ְתִּי", "הו123תי", "hu12+a3ti"), ("הֻ1ְ2+ַ3ְנוּ", "הו123נו", "hu12+a3nu"),
+        ("הֻ1ְ2+ַ3ְתָּ", "הו123ת", "hu12+a3ta"), ("הֻ1ְ2+ַ3ְתְּ", "הו123ת", "hu12+a3t"),
+        ("הֻ1ְ2+ַ3ְתֶּם", "הו123תם", "hu12+a3tem"), ("הֻ1ְ2+ַ3ְתֶּן", "הו123תן", "hu12+a3ten"),
+        ("הֻ1ְ2+ַ3", "הו123", "hu12+a3"), ("הֻ1ְ2+ְ3ָה", "הו123ה", "hu12+e3a"),
+        ("הֻ1ְ2+ְ3וּ", "הו123ו", "hu12+e3u"),
+        ("אֻ1ְ2+ַ3", "או123", "u12+a3"), ("נֻ1ְ2+ַ3", "נו123", "nu12+a3"),
+        ("תֻּ1ְ2+ַ3", "תו123", "tu12+a3"), ("תֻּ1ְ2+ְ3ִי", "תו123י", "tu12+e3i"),
+        ("תֻּ1ְ2+ְ3וּ", "תו123ו", "tu12+e3u"), ("תֻּ1ְ2+ַ3ְנָה", "תו123נה", "tu12+a3na"),
+        ("יֻ1ְ2+ַ3", "יו123", "yu12+a3"), ("תֻּ1ְ2+ַ3", "תו123", "tu12+a3"),
+        ("יֻ1ְ2+ְ3וּ", "יו123ו", "yu12+e3u"), ("תֻּ1ְ2+ַ3ְנָה", "תו123נה", "tu12+a3na"),
+    ],
+};
+
+#[rustfmt::skip]
+const HITPAEL: Paradigm = Paradigm {
+    infinitive: Some(("לְהִתְ1+ַ2=ֵ3", "להת123", "lehit1+a2=e3")),
+    forms: &[
+        ("מִתְ1+ַ2=ֵ3", "מת123", "mit1+a2=e3"), ("מִתְ1+ַ2=ֶ3ֶת", "מת123ת", "mit1+a2=e3et"),
+        ("מִתְ1+ַ2=ְ3ִים", "מת123ים", "mit1+a2=3im"), ("מִתְ1+ַ2=ְ3וֹת", "מת123ות", "mit1+a2=3ot"),
+        ("הִתְ1+ַ2=ַ3ְתִּי", "הת123תי", "hit1+a2=a3ti"), ("הִתְ1+ַ2=ַ3ְנוּ", "הת123נו", "hit1+a2=a3nu"),
+        ("הִתְ1+ַ2=ַ3ְתָּ", "הת123ת", "hit1+a2=a3ta"), ("הִתְ1+ַ2=ַ3ְתְּ", "הת123ת", "hit1+a2=a3t"),
+        ("הִתְ1+ַ2=ַ3ְתֶּם", "הת123תם", "hit1+a2=a3tem"), ("הִתְ1+ַ2=ַ3ְתֶּן", "הת123תן", "hit1+a2=a3ten"),
+        ("הִתְ1+ַ2=ֵ3", "הת123", "hit1+a2=e3"), ("הִתְ1+ַ2=ְ3ָה", "הת123ה", "hit1+a2=3a"),
+        ("הִתְ1+ַ2=ְ3וּ", "הת123ו", "hit1+a2=3u"),
+        ("אֶתְ1+ַ2=ֵ3", "את123", "et1+a2=e3"), ("נִתְ1+ַ2=ֵ3", "נת123", "nit1+a2=e3"),
+        ("תִּתְ1+ַ2=ֵ3", "תת123", "tit1+a2=e3"), ("תִּתְ1+ַ2=ְ3ִי", "תת123י", "tit1+a2=3i"),
+        ("תִּתְ1+ַ2=ְ3וּ", "תת123ו", "tit1+a2=3u"), ("תִּתְ1+ַ2=ֵ3ְנָה", "תת123נה", "tit1+a2=e3na"),
+        ("יִתְ1+ַ2=ֵ3", "ית123", "yit1+a2=e3"), ("תִּתְ1+ַ2=ֵ3", "תת123", "tit1+a2=e3"),
+        ("יִתְ1+ַ2=ְ3וּ", "ית123ו", "yit1+a2=3u"), ("תִּתְ1+ַ2=ֵ3ְנָה", "תת123נה", "tit1+a2=e3na"),
+        ("הִתְ1+ַ2=ֵ3", "הת123", "hit1+a2=e3"), ("הִתְ1+ַ2=ְ3ִי", "הת123י", "hit1+a2=3i"),
+        ("הִתְ1+ַ2=ְ3וּ", "הת123ו", "hit1+a2=3u"), ("הִתְ1+ַ2=ֵ3ְנָה", "הת123נה", "hit1+a2=e3na"),
+    ],
+};
+
+/// A root letter, `sin` is set for שׂ.
+#[derive(Clone, Copy)]
+struct RootLetter {
+    letter: char,
+    sin: bool,
+}
+
+impl RootLetter {
+    fn transcription(&self, hard: bool) -> &'static str {
+        match self.letter {
+            'ב' if hard => "b",
+            'ב' | 'ו' => "v",
+            'כ' if hard => "k",
+            'כ' | 'ח' => "ch",
+            'פ' if hard => "p",
+            'פ' => "f",
+            'ג' => "g",
+            'ד' => "d",
+            'ה' => "h",
+            'ז' => "z",
+            'ט' | 'ת' => "t",
+            'י' => "y",
+            'ל' => "l",
+            'מ' => "m",
+            'נ' => "n",
+            'ס' => "s",
+            'צ' => "tz",
+            'ק' => "k",
+            'ר' => "r",
+            'ש' if self.sin => "s",
+            'ש' => "sh",
+            _ => "",
+        }
+    }
+}
+
+fn parse_root(root: &str) -> Vec<RootLetter> {
+    let mut letters: Vec<RootLetter> = Vec::new();
+    for c in root.nfd() {
+        if is_hebrew_letter(c) {
+            letters.push(RootLetter {
+                letter: unfinal(c),
+                sin: false,
+            });
+        } else if c == '\u{05C2}' {
+            if let Some(last) = letters.last_mut() {
+                last.sin = true;
+            }
+        }
+    }
+    letters
+}
+
+fn final_form(c: char) -> char {
+    match c {
+        'כ' => 'ך',
+        'מ' => 'ם',
+        'נ' => 'ן',
+        'פ' => 'ף',
+        'צ' => 'ץ',
+        _ => c,
+    }
+}
+
+fn is_begadkefat(c: char) -> bool {
+    matches!(c, 'ב' | 'ג' | 'ד' | 'כ' | 'פ' | 'ת')
+}
+
+/// Fills the root letters into a template, see `Paradigm`.
+fn fill(template: &str, root: &[RootLetter], vowelled: bool) -> String {
+    let mut out = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        let Some(slot) = c.to_digit(10) else {
+            out.push(c);
+            continue;
+        };
+        let letter = root[slot as usize - 1];
+        let mark = chars.next_if(|c| matches!(c, '+' | '='));
+        out.push(letter.letter);
+        if vowelled {
+            if letter.letter == 'ש' {
+                out.push(if letter.sin { '\u{05C2}' } else { '\u{05C1}' });
+            }
+            let dagesh = match mark {
+                Some('+') => is_begadkefat(letter.letter),
+                Some(_) => !matches!(letter.letter, 'א' | 'ה' | 'ח' | 'ע' | 'ר'),
+                None => false,
+            };
+            if dagesh {
+                out.push('\u{05BC}');
+            }
+        }
+    }
+    // final letters, a word-final kaf keeps its shva
+    let last = out.char_indices().rev().find(|(_, c)| is_hebrew_letter(*c));
+    if let Some((i, c)) = last {
+        let is_last = i + c.len_utf8() == out.len();
+        out.replace_range(i..i + c.len_utf8(), &final_form(c).to_string());
+        if vowelled && is_last && c == 'כ' {
+            out.push('\u{05B0}');
+        }
+    }
+    out.nfd().collect()
+}
+
+fn transcribe(template: &str, root: &[RootLetter]) -> String {
+    let mut out = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c.to_digit(10) {
+            Some(slot) => {
+                let hard = chars.next_if(|c| matches!(c, '+' | '=')).is_some();
+                out.push_str(root[slot as usize - 1].transcription(hard));
+            }
+            None => out.push(c),
+        }
+    }
+    out
+}
+
+/// Hitpa'el swaps its ת with a sibilant first root letter and voices or emphasizes it
+/// after ז and צ: הִסְתַּדֵּר, הִזְדַּקֵּן, הִצְטַלֵּם.
+fn metathesis<'a>(
+    templates: (&'a str, &'a str, &'a str),
+    first: RootLetter,
+) -> (String, String, String) {
+    let (vowelled, male, transcription) = templates;
+    let (infix, infix_vowelled, infix_latin) = match first.letter {
+        'ס' | 'ש' => ('ת', "תּ", "t"),
+        'ז' => ('ד', "דּ", "d"),
+        'צ' => ('ט', "ט", "t"),
+        _ => return (vowelled.into(), male.into(), transcription.into()),
+    };
+    (
+        vowelled.replacen("תְ1+", &format!("1ְ{}", infix_vowelled), 1),
+        male.replacen("ת1", &format!("1{}", infix), 1),
+        transcription.replacen("t1+", &format!("1{}", infix_latin), 1),
+    )
+}
+
+fn paradigm(binyan: &str) -> &'static Paradigm {
+    match binyan {
+        "NIF'AL" => &NIFAL,
+        "PI'EL" => &PIEL,
+        "PU'AL" => &PUAL,
+        "HIF'IL" => &HIFIL,
+        "HUF'AL" => &HUFAL,
+        "HITPA'EL" => &HITPAEL,
+        _ => &PAAL,
+    }
+}
+
+fn conjugate_forms(binyan: &str, root: &[RootLetter]) -> Vec<WordForm> {
+    paradigm(binyan)
+        .forms
+        .iter()
+        .zip(CELLS)
+        .map(|(templates, (tense, person, number, gender))| {
+            let (vowelled, male, transcription) = if binyan == "HITPA'EL" {
+                metathesis(*templates, root[0])
+            } else {
+                let (v, m, t) = templates;
+                (v.to_string(), m.to_string(), t.to_string())
+            };
+            let form = fill(&male, root, false);
+            WordForm {
+                tense: tense.to_string(),
+                person: person.to_string(),
+                number: number.to_string(),
+                gender: gender.to_string(),
+                form_normalized: form.clone(),
+                form,
+                transcription: transcribe(&transcription, root),
+                meaning: String::new(),
+                form_vowelled: Some(fill(&vowelled, root, true)),
+                irregular: false,
+                state: None,
+                possessor: None,
+            }
+        })
+        .collect()
+}
+
+/// The infinitive as (ktiv male, vowelled, transcription), `None` for passive binyanim.
+fn conjugate_infinitive(binyan: &str, root: &[RootLetter]) -> Option<(String, String, String)> {
+    let templates = paradigm(binyan).infinitive?;
+    let (vowelled, male, transcription) = if binyan == "HITPA'EL" {
+        metathesis(templates, root[0])
+    } else {
+        let (v, m, t) = templates;
+        (v.to_string(), m.to_string(), t.to_string())
+    };
+    Some((
+        fill(&male, root, false),
+        fill(&vowelled, root, true),
+        transcribe(&transcription, root),
+    ))
+}
+
+/// Whether the regular paradigm of `binyan` is the right one for a root of these gizrot:
+/// regular roots, and roots whose only weakness is a medial ר in a binyan that does not
+/// double it (ר takes no dagesh).
+pub(crate) fn has_regular_paradigm(root: &str, gizrot: &[String], binyan: &str) -> bool {
+    let regular_resh = gizrot == [AYIN_GUTTURAL]
+        && normalize_root(root).chars().nth(1) == Some('ר')
+        && !matches!(binyan, "PI'EL" | "PU'AL" | "HITPA'EL");
+    gizrot == [SHLEMIM] || regular_resh
+}
+
+/// Forms the regular paradigm gives for any three-letter root, weak or not, in a canonical
+/// binyan. The infinitive, if the binyan has one, comes first with tense "infinitive" and no
+/// person, number or gender. `None` for other roots and unknown binyanim.
+pub(crate) fn regular_paradigm(root: &str, binyan: &str) -> Option<Vec<WordForm>> {
+    if !BINYANIM.contains(&binyan) {
+        return None;
+    }
+    let letters = parse_root(root);
+    if letters.len() != 3 {
+        return None;
+    }
+    let mut forms = Vec::new();
+    if let Some((word, vowelled, transcription)) = conjugate_infinitive(binyan, &letters) {
+        forms.push(WordForm {
+            tense: "infinitive".to_string(),
+            person: String::new(),
+            number: String::new(),
+            gender: String::new(),
+            form: word.clone(),
+            form_normalized: word,
+            transcription,
+            meaning: String::new(),
+            form_vowelled: Some(vowelled),
+            irregular: false,
+            state: None,
+            possessor: None,
+        });
+    }
+    forms.extend(conjugate_forms(binyan, &letters));
+    Some(forms)
+}
+
+/// Ktiv male templates of every form of a binyan, the infinitive included. Hitpa'el also gets
+/// its templates with the ת after a sibilant first root letter.
+pub(crate) fn male_templates(binyan: &str) -> Vec<String> {
+    let paradigm = paradigm(binyan);
+    let mut templates: Vec<String> = Vec::new();
+    let all = paradigm
+        .infinitive
+        .iter()
+        .map(|(_, male, _)| *male)
+        .chain(paradigm.forms.iter().map(|(_, male, _)| *male));
+    for template in all {
+        let mut variants = vec![template.to_string()];
+        if binyan == "HITPA'EL" {
+            for infix in ["1ת", "1ד", "1ט"] {
+                variants.push(template.replacen("ת1", infix, 1));
+            }
+        }
+        for variant in variants {
+            if !templates.contains(&variant) {
+                templates.push(variant);
+            }
+        }
+    }
+    templates
+}
+
+#[cfg_attr(feature = "wasm-support", wasm_bindgen)]
+impl WordData {
+    /// Conjugates a regular (shlemim) root in a binyan by rule, for roots and binyanim the
+    /// dataset lacks. Roots with a medial ר are regular too outside PI'EL, PU'AL and
+    /// HITPA'EL. PI'EL and HIF'IL come with their PU'AL and HUF'AL passives, asking for
+    /// a passive binyan gives the same entry as its active one. Meanings are left empty and
+    /// the entry is marked `synthetic`.
+    pub fn conjugate(root: &str, binyan: &str) -> Result<WordData, ConjugateError> {
+        let binyan = canonical_binyan(binyan)
+            .ok_or_else(|| error(format!("unknown binyan '{}'", binyan)))?;
+        let letters = parse_root(root);
+        if letters.len() != 3 {
+            return Err(error(format!("'{}' is not a three-letter root", root)));
+        }
+        let (binyan, passive_binyan) = match binyan.as_str() {
+            "PI'EL" | "PU'AL" => ("PI'EL", Some("PU'AL")),
+            "HIF'IL" | "HUF'AL" => ("HIF'IL", Some("HUF'AL")),
+            other => (other, None),
+        };
+        let plain: String = letters.iter().map(|l| l.letter).collect();
+        let gizrot = classify_root(&plain);
+        if !has_regular_paradigm(&plain, &gizrot, binyan) {
+            return Err(error(format!(
+                "only regular roots can be conjugated, '{}' is {}",
+                root,
+                gizrot.join(", ")
+            )));
+        }
+        let (word, _, transcription) = conjugate_infinitive(binyan, &letters).unwrap();
+        let slug: String = binyan.chars().filter(|c| c.is_ascii_alphabetic()).collect();
+        let mut word = WordData {
+            url_id: format!("synthetic-{}-{}", plain, slug.to_lowercase()),
+            word_normalized: word.clone(),
+            word,
+            word_en: String::new(),
+            transcription,
+            root: plain
+                .chars()
+                .map(String::from)
+                .collect::<Vec<_>>()
+                .join(" - "),
+            forms: conjugate_forms(binyan, &letters),
+            binyan: binyan.to_string(),
+            passive: passive_binyan.map(|passive| conjugate_forms(passive, &letters)),
+            passive_binyan: passive_binyan.map(String::from),
+            gizrot,
+            synthetic: true,
+            irregular_cells: vec![],
+            infinitives: vec![],
+            part_of_speech: "verb".to_string(),
+            mishkal: None,
+            gender: None,
+            government: vec![],
+        };
+        word.infinitives = infinitive_forms(&word);
+        Ok(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use prost::Message;
+
+    use crate::gizra::SHLEMIM;
+    use crate::proto::worddata::WordDataList;
+    use crate::proto::{convert_pb_to_dto, WORDS_PB};
+    use crate::template::prepare_vowelled;
+    use crate::test_data::{lesader, lichtov};
+    use crate::word_dto::{WordData, WordForm};
+
+    /// Whether every form of the dataset is generated in the same cell, with the same
+    /// spelling and, when the dataset has it, the same niqqud.
+    fn reproduces(generated: &[WordForm], expected: &[WordForm]) -> bool {
+        expected.iter().all(|e| {
+            generated.iter().any(|g| {
+                (&g.tense, &g.person, &g.number, &g.gender)
+                    == (&e.tense, &e.person, &e.number, &e.gender)
+                    && g.form_normalized == e.form_normalized
+                    && match (&g.form_vowelled, &e.form_vowelled) {
+                        (Some(g), Some(e)) => prepare_vowelled(g) == prepare_vowelled(e),
+                        _ => true,
+                    }
+            })
+        })
+    }
+
+    #[test]
+    fn conjugate_dataset_strong_roots() {
+        let word_list: WordDataList = Message::decode(WORDS_PB).unwrap();
+        let words = convert_pb_to_dto(word_list.words);
+        // binyan -> (strong-root verbs, verbs reproduced)
+        let mut counts: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+        for expected in words
+            .iter()
+            .filter(|w| w.is_verb() && w.gizrot == [SHLEMIM])
+        {
+            let Ok(generated) = WordData::conjugate(&expected.root, &expected.binyan) else {
+                continue;
+            };
+            let passive_reproduced = match (&expected.passive, &generated.passive) {
+                (Some(e), Some(g)) => reproduces(g, e),
+                (Some(_), None) => false,
+                (None, _) => true,
+            };
+            let count = counts.entry(expected.binyan.clone()).or_default();
+            count.0 += 1;
+            if generated.word_normalized == expected.word_normalized
+                && reproduces(&generated.forms, &expected.forms)
+                && passive_reproduced
+            {
+                count.1 += 1;
+            }
+        }
+        for (binyan, (total, reproduced)) in &counts {
+            println!(
+                "{}: {} of {} strong-root verbs reproduced",
+                binyan, reproduced, total
+            );
+        }
+        let total: usize = counts.values().map(|c| c.0).sum();
+        let reproduced: usize = counts.values().map(|c| c.1).sum();
+        assert!(total > 0, "no strong-root verb in the dataset");
+        assert!(
+            reproduced * 2 >= total,
+            "only {} of {} strong-root verbs reproduced",
+            reproduced,
+            total
+        );
+    }
+
+    fn assert_same_forms(generated: &[WordForm], expected: &[WordForm]) {
+        assert_eq!(generated.len(), expected.len());
+        for (g, e) in generated.iter().zip(expected) {
+            assert_eq!(
+                (&g.tense, &g.person, &g.number, &g.gender),
+                (&e.tense, &e.person, &e.number, &e.gender)
+            );
+            assert_eq!(g.form, e.form);
+            assert_eq!(g.form_vowelled, e.form_vowelled);
+            assert_eq!(g.transcription, e.transcription);
+        }
+    }
+
+    #[test]
+    fn conjugate_like_dataset() {
+        let expected = lichtov();
+        let generated = WordData::conjugate("כ-ת-ב", "paal").unwrap();
+        assert!(generated.synthetic && !expected.synthetic);
+        assert_eq!(generated.url_id, "synthetic-כתב-paal");
+        assert_eq!(generated.word, expected.word);
+        assert_eq!(generated.transcription, expected.transcription);
+        assert_eq!(generated.root, expected.root);
+        assert_same_forms(&generated.forms, &expected.forms);
+
+        let expected = lesader();
+        let generated = WordData::conjugate("סדר", "PU'AL").unwrap();
+        assert_eq!(generated.binyan, "PI'EL");
+        assert_eq!(generated.passive_binyan.as_deref(), Some("PU'AL"));
+        assert_eq!(generated.transcription, expected.transcription);
+        assert_same_forms(&generated.forms, &expected.forms);
+        assert_same_forms(
+            generated.passive.as_ref().unwrap(),
+            expected.passive.as_ref().unwrap(),
+        );
+    }
+
+    #[test]
+    fn conjugate_other_binyanim() {
+        let word = WordData::conjugate("ס-ד-ר", "HITPA'EL").unwrap();
+        assert_eq!(word.word, "להסתדר");
+        assert_eq!(word.transcription, "lehistader");
+        assert_eq!(word.forms[10].form_vowelled.as_deref(), Some("הִסְתַּדֵּר"));
+        let word = WordData::conjugate("כתב", "hifil").unwrap();
+        assert_eq!(word.word, "להכתיב");
+        assert_eq!(word.transcription, "lehachtiv");
+        assert_eq!(word.passive.unwrap()[10].form, "הוכתב");
+        let word = WordData::conjugate("שׂ-ג-ב", "NIF'AL").unwrap();
+        assert_eq!(word.forms[10].transcription, "nisgav");
+        assert_eq!(word.forms[10].form_vowelled.as_deref(), Some("נִשְׂגַּב"));
+        let word = WordData::conjugate("מ-ל-כ", "PA'AL").unwrap();
+        assert_eq!(word.forms[10].form, "מלך");
+        assert_eq!(word.forms[10].form_vowelled.as_deref(), Some("מָלַךְ"));
+        assert_eq!(word.forms[10].transcription, "malach");
+
+        // ר is only weak where the binyan doubles it
+        let word = WordData::conjugate("ס-ר-ק", "PA'AL").unwrap();
+        assert_eq!(word.word, "לסרוק");
+        assert_eq!(word.forms[10].form_vowelled.as_deref(), Some("סָרַק"));
+        assert_eq!(word.forms[10].transcription, "sarak");
+        assert_eq!(
+            WordData::conjugate("סרק", "hifil").unwrap().forms[10].form,
+            "הסריק"
+        );
+        assert!(WordData::conjugate("ס-ר-ק", "PI'EL").is_err());
+        assert!(WordData::conjugate("ס-ר-ק", "PU'AL").is_err());
+
+        assert!(WordData::conjugate("א-כ-ל", "PA'AL").is_err());
+        assert!(WordData::conjugate("כתב", "PA'IL").is_err());
+        assert!(WordData::conjugate("כת", "PA'AL").is_err());
+    }
+}
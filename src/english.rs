@@ -0,0 +1,355 @@
+use std::collections::{HashMap, HashSet};
+
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref IRREGULAR_VERBS: HashMap<&'static str, &'static str> = {
+        let pairs = [
+            ("ate", "eat"),
+            ("eaten", "eat"),
+            ("was", "be"),
+            ("were", "be"),
+            ("been", "be"),
+            ("became", "become"),
+            ("began", "begin"),
+            ("begun", "begin"),
+            ("bent", "bend"),
+            ("bit", "bite"),
+            ("bitten", "bite"),
+            ("blew", "blow"),
+            ("blown", "blow"),
+            ("broke", "break"),
+            ("broken", "break"),
+            ("brought", "bring"),
+            ("built", "build"),
+            ("burnt", "burn"),
+            ("bought", "buy"),
+            ("caught", "catch"),
+            ("chose", "choose"),
+            ("chosen", "choose"),
+            ("came", "come"),
+            ("dealt", "deal"),
+            ("dug", "dig"),
+            ("did", "do"),
+            ("done", "do"),
+            ("drew", "draw"),
+            ("drawn", "draw"),
+            ("dreamt", "dream"),
+            ("drank", "drink"),
+            ("drunk", "drink"),
+            ("drove", "drive"),
+            ("driven", "drive"),
+            ("fallen", "fall"),
+            ("fed", "feed"),
+            ("felt", "feel"),
+            ("fought", "fight"),
+            ("fled", "flee"),
+            ("flew", "fly"),
+            ("flown", "fly"),
+            ("forgot", "forget"),
+            ("forgotten", "forget"),
+            ("forgave", "forgive"),
+            ("forgiven", "forgive"),
+            ("froze", "freeze"),
+            ("frozen", "freeze"),
+            ("got", "get"),
+            ("gotten", "get"),
+            ("gave", "give"),
+            ("given", "give"),
+            ("went", "go"),
+            ("gone", "go"),
+            ("grew", "grow"),
+            ("grown", "grow"),
+            ("hung", "hang"),
+            ("had", "have"),
+            ("heard", "hear"),
+            ("hid", "hide"),
+            ("hidden", "hide"),
+            ("held", "hold"),
+            ("kept", "keep"),
+            ("knelt", "kneel"),
+            ("knew", "know"),
+            ("known", "know"),
+            ("laid", "lay"),
+            ("led", "lead"),
+            ("leapt", "leap"),
+            ("learnt", "learn"),
+            ("lent", "lend"),
+            ("lain", "lie"),
+            ("lit", "light"),
+            ("lost", "lose"),
+            ("made", "make"),
+            ("meant", "mean"),
+            ("met", "meet"),
+            ("paid", "pay"),
+            ("rode", "ride"),
+            ("ridden", "ride"),
+            ("rang", "ring"),
+            ("rung", "ring"),
+            ("rose", "rise"),
+            ("risen", "rise"),
+            ("ran", "run"),
+            ("said", "say"),
+            ("seen", "see"),
+            ("sought", "seek"),
+            ("sold", "sell"),
+            ("sent", "send"),
+            ("shook", "shake"),
+            ("shaken", "shake"),
+            ("shone", "shine"),
+            ("shot", "shoot"),
+            ("shown", "show"),
+            ("shrank", "shrink"),
+            ("sang", "sing"),
+            ("sung", "sing"),
+            ("sank", "sink"),
+            ("sunk", "sink"),
+            ("sat", "sit"),
+            ("slept", "sleep"),
+            ("slid", "slide"),
+            ("spoke", "speak"),
+            ("spoken", "speak"),
+            ("spent", "spend"),
+            ("spun", "spin"),
+            ("stood", "stand"),
+            ("stole", "steal"),
+            ("stolen", "steal"),
+            ("stuck", "stick"),
+            ("stung", "sting"),
+            ("struck", "strike"),
+            ("swore", "swear"),
+            ("sworn", "swear"),
+            ("swept", "sweep"),
+            ("swam", "swim"),
+            ("swum", "swim"),
+            ("took", "take"),
+            ("taken", "take"),
+            ("taught", "teach"),
+            ("tore", "tear"),
+            ("torn", "tear"),
+            ("told", "tell"),
+            ("thought", "think"),
+            ("threw", "throw"),
+            ("thrown", "throw"),
+            ("understood", "understand"),
+            ("woke", "wake"),
+            ("woken", "wake"),
+            ("wore", "wear"),
+            ("worn", "wear"),
+            ("wept", "weep"),
+            ("won", "win"),
+            ("wrote", "write"),
+            ("written", "write"),
+        ];
+        pairs.into_iter().collect()
+    };
+    static ref BRITISH_SPELLINGS: HashMap<&'static str, &'static str> = {
+        let pairs = [
+            ("aeroplane", "airplane"),
+            ("analogue", "analog"),
+            ("catalogue", "catalog"),
+            ("cheque", "check"),
+            ("dialogue", "dialog"),
+            ("defence", "defense"),
+            ("grey", "gray"),
+            ("jewellery", "jewelry"),
+            ("licence", "license"),
+            ("manoeuvre", "maneuver"),
+            ("mould", "mold"),
+            ("offence", "offense"),
+            ("plough", "plow"),
+            ("practise", "practice"),
+            ("pretence", "pretense"),
+            ("programme", "program"),
+            ("pyjamas", "pajamas"),
+            ("sceptical", "skeptical"),
+            ("storey", "story"),
+            ("tyre", "tire"),
+        ];
+        pairs.into_iter().collect()
+    };
+    // words ending in -our, -ise and -tre that are spelled the same on both sides of the Atlantic
+    static ref SPELLING_EXCEPTIONS: HashSet<&'static str> = {
+        let words = [
+            "our", "four", "hour", "your", "pour", "tour", "sour", "flour", "devour", "detour",
+            "contour", "amateur", "rise", "arise", "wise", "otherwise", "likewise", "advise",
+            "devise", "revise", "supervise", "televise", "improvise", "comprise", "compromise",
+            "surprise", "enterprise", "exercise", "promise", "premise", "raise", "praise",
+            "despise", "disguise", "franchise", "advertise", "chastise", "concise", "precise",
+            "noise", "poise", "cruise", "bruise", "treatise", "expertise", "merchandise",
+            "paradise", "demise", "incise", "excise", "circumcise", "mise",
+        ];
+        words.into_iter().collect()
+    };
+}
+
+/// Normalizes English text for translation search: lowercases it, drops the leading "to " of
+/// an infinitive, folds British spellings into American ones, maps irregular past forms to
+/// their base verb and applies a light suffix stemmer to every word.
+/// Spellings are folded for complete words only: a partially typed British word such as
+/// "organis" is kept as typed and only matches keys sharing that prefix.
+/// Index keys and queries must both go through this function to be comparable.
+pub(crate) fn normalize_en(val: &str) -> String {
+    let lower = val.trim().to_lowercase();
+    let lower = lower.strip_prefix("to ").unwrap_or(&lower);
+    lower
+        .split_whitespace()
+        .map(normalize_en_word)
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn normalize_en_word(word: &str) -> String {
+    if let Some(base) = IRREGULAR_VERBS.get(word) {
+        return stem(base);
+    }
+    stem(&fold_spelling(word))
+}
+
+fn fold_spelling(word: &str) -> String {
+    if let Some(american) = BRITISH_SPELLINGS.get(word) {
+        return american.to_string();
+    }
+    if SPELLING_EXCEPTIONS.contains(word) {
+        return word.to_string();
+    }
+    // (british suffix, american suffix, suffix of the british base form); the base form is
+    // checked against the exceptions so that "surprising" is left alone like "surprise"
+    const RULES: [(&str, &str, &str); 15] = [
+        ("isation", "ization", "ise"),
+        ("ising", "izing", "ise"),
+        ("ised", "ized", "ise"),
+        ("ises", "izes", "ise"),
+        ("ise", "ize", "ise"),
+        ("ysed", "yzed", "yse"),
+        ("ysing", "yzing", "yse"),
+        ("yse", "yze", "yse"),
+        ("ours", "ors", "our"),
+        ("oured", "ored", "our"),
+        ("ouring", "oring", "our"),
+        ("our", "or", "our"),
+        ("tres", "ters", "tre"),
+        ("tre", "ter", "tre"),
+        ("elled", "eled", "el"),
+    ];
+    for (british, american, base_suffix) in RULES {
+        if let Some(stem) = word.strip_suffix(british) {
+            let base = format!("{}{}", stem, base_suffix);
+            if stem.chars().count() < 3 || SPELLING_EXCEPTIONS.contains(base.as_str()) {
+                continue;
+            }
+            return format!("{}{}", stem, american);
+        }
+    }
+    if let Some(stem) = word.strip_suffix("elling") {
+        if stem.chars().count() >= 3 {
+            return format!("{}eling", stem);
+        }
+    }
+    word.to_string()
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+fn stem(word: &str) -> String {
+    if !word.chars().all(|c| c.is_ascii_alphabetic()) || word.len() <= 3 {
+        return word.to_string();
+    }
+    let mut stem = word.to_string();
+    if let Some(s) = word.strip_suffix("ies").filter(|s| s.len() <= 2) {
+        // "lies", "ties": the plural of a word in -ie
+        stem = format!("{}ie", s);
+    } else if let Some(s) = word.strip_suffix("ies") {
+        stem = format!("{}y", s);
+    } else if let Some(s) = word.strip_suffix("ied") {
+        stem = format!("{}y", s);
+    } else if word.ends_with("ss") || word.ends_with("us") || word.ends_with("is") {
+        // "class", "bus" and "analysis" are not plurals
+    } else if let Some(s) = word
+        .strip_suffix("es")
+        .filter(|s| s.ends_with("sh") || s.ends_with("ch") || s.ends_with('x') || s.ends_with('s'))
+    {
+        stem = s.to_string();
+    } else if let Some(s) = word.strip_suffix("es").filter(|s| s.ends_with('o')) {
+        // "does", "goes", "potatoes"
+        stem = s.to_string();
+    } else if let Some(s) = word.strip_suffix('s') {
+        stem = s.to_string();
+    } else if let Some(s) = word.strip_suffix("ing").filter(|s| s.len() >= 3) {
+        stem = undouble(s);
+    } else if let Some(s) = word
+        .strip_suffix("ed")
+        .filter(|s| s.len() >= 3 && !s.ends_with('e'))
+    {
+        stem = undouble(s);
+    } else if let Some(s) = word.strip_suffix('d').filter(|s| s.ends_with('e')) {
+        // "hoped", "agreed"
+        stem = s.to_string();
+    }
+    // the final silent "e" is dropped so that "write" and "writing" share a stem
+    if stem.len() > 3 && stem.ends_with('e') && !stem.ends_with("ee") {
+        stem.pop();
+    }
+    stem
+}
+
+fn undouble(stem: &str) -> String {
+    let chars: Vec<char> = stem.chars().collect();
+    let n = chars.len();
+    if n >= 2 && chars[n - 1] == chars[n - 2] && !is_vowel(chars[n - 1]) {
+        let last = chars[n - 1];
+        if !matches!(last, 'l' | 's' | 'z') {
+            return chars[..n - 1].iter().collect();
+        }
+    }
+    stem.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::english::normalize_en;
+
+    #[test]
+    fn stems_regular_inflections() {
+        assert_eq!(normalize_en("walking"), normalize_en("walk"));
+        assert_eq!(normalize_en("walked"), normalize_en("walk"));
+        assert_eq!(normalize_en("walks"), normalize_en("walk"));
+        assert_eq!(normalize_en("running"), normalize_en("run"));
+        assert_eq!(normalize_en("carried"), normalize_en("carry"));
+        assert_eq!(normalize_en("hoping"), normalize_en("hope"));
+        assert_eq!(normalize_en("washes"), normalize_en("wash"));
+        assert_eq!(normalize_en("falling"), normalize_en("fall"));
+        assert_eq!(normalize_en("does"), normalize_en("do"));
+        assert_eq!(normalize_en("goes"), normalize_en("go"));
+        assert_eq!(normalize_en("potatoes"), normalize_en("potato"));
+        assert_eq!(normalize_en("lies"), normalize_en("lie"));
+        assert_eq!(normalize_en("classes"), normalize_en("class"));
+        assert_eq!(normalize_en("addresses"), normalize_en("address"));
+    }
+
+    #[test]
+    fn maps_irregular_past() {
+        assert_eq!(normalize_en("wrote"), normalize_en("write"));
+        assert_eq!(normalize_en("written"), normalize_en("write"));
+        assert_eq!(normalize_en("went"), normalize_en("go"));
+    }
+
+    #[test]
+    fn folds_british_spelling() {
+        assert_eq!(normalize_en("organise"), normalize_en("organize"));
+        assert_eq!(normalize_en("organising"), normalize_en("organize"));
+        assert_eq!(normalize_en("colours"), normalize_en("color"));
+        assert_eq!(normalize_en("centre"), normalize_en("center"));
+        assert_eq!(normalize_en("travelled"), normalize_en("traveled"));
+        assert_eq!(normalize_en("tour"), "tour");
+        assert_eq!(normalize_en("surprise"), normalize_en("surprising"));
+    }
+
+    #[test]
+    fn lowercases_and_strips_infinitive_marker() {
+        assert_eq!(normalize_en("To Write"), normalize_en("write"));
+        assert_eq!(normalize_en("Looking  for"), normalize_en("look for"));
+    }
+}
@@ -0,0 +1,496 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "wasm-support")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::query::{
+    canonical_binyan, canonical_gender, canonical_number, canonical_person, canonical_tense,
+};
+use crate::query_parser::ParseError;
+use crate::word_dto::{MatchedForm, SearchResult, WordData, WordForm};
+
+/// Grammatical features of a single form, with the same values as `WordForm`
+/// ("past", "3rd", "singular", "f", "all", ...). The infinitive has tense "infinitive" and
+/// no person, number or gender.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Features {
+    pub tense: Option<String>,
+    pub person: Option<String>,
+    pub number: Option<String>,
+    pub gender: Option<String>,
+    pub binyan: Option<String>,
+    pub passive: Option<bool>,
+}
+
+/// Error returned by `WordIndex::inflect`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InflectError {
+    /// Neither a url_id nor the infinitive of any word.
+    UnknownWord(String),
+    /// The word exists but has no form with the requested features.
+    NoSuchForm { word: String, features: String },
+}
+
+impl fmt::Display for InflectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InflectError::UnknownWord(word) => write!(f, "unknown word '{}'", word),
+            InflectError::NoSuchForm { word, features } => {
+                write!(f, "'{}' has no form with {}", word, features)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InflectError {}
+
+/// `value` in the spelling used by `WordForm`, values the canonicalizer does not know are
+/// kept as they are and match nothing.
+fn canonical(value: &Option<String>, canonicalize: fn(&str) -> Option<String>) -> Option<String> {
+    value
+        .as_ref()
+        .map(|v| canonicalize(v).unwrap_or_else(|| v.clone()))
+}
+
+fn matches_value(wanted: &Option<String>, value: &str, all_matches: bool) -> bool {
+    match wanted {
+        None => true,
+        Some(wanted) => wanted == value || (all_matches && value == "all"),
+    }
+}
+
+impl Features {
+    /// Every form of `word` having these features, features left unset match anything and a
+    /// form marked "all" for person or gender matches any requested person or gender.
+    /// Values are read like `Query` reads them ("3", "pl", "PI'EL", ...). The infinitive,
+    /// which has no person, number nor gender, is selected when none of those is set.
+    pub fn select(&self, word: &WordData) -> Vec<(MatchedForm, WordForm)> {
+        let tense = canonical(&self.tense, canonical_tense);
+        let person = canonical(&self.person, canonical_person);
+        let number = canonical(&self.number, canonical_number);
+        let gender = canonical(&self.gender, canonical_gender);
+        let binyan = canonical(&self.binyan, canonical_binyan);
+        let mut selected = Vec::new();
+        let wants_passive = self.passive == Some(true);
+        let wants_active = self.passive != Some(true);
+        let wants_infinitive = match tense.as_deref() {
+            Some(tense) => tense == "infinitive",
+            None => person.is_none() && number.is_none() && gender.is_none(),
+        };
+        if word.is_verb()
+            && wants_active
+            && wants_infinitive
+            && matches_value(&binyan, &word.binyan, false)
+        {
+            selected.push((MatchedForm::new(0, 0), word.infinitive_form()));
+        }
+        if tense.as_deref() == Some("infinitive") {
+            return selected;
+        }
+        let mut candidates: Vec<(usize, &Vec<WordForm>, &str)> = Vec::new();
+        if wants_active {
+            candidates.push((1, &word.forms, &word.binyan));
+        }
+        if let (true, Some(passive), Some(binyan)) = (
+            wants_passive || self.passive.is_none(),
+            &word.passive,
+            &word.passive_binyan,
+        ) {
+            candidates.push((2, passive, binyan));
+        }
+        for (kind, forms, form_binyan) in candidates {
+            if !matches_value(&binyan, form_binyan, false) {
+                continue;
+            }
+            for (index, form) in forms.iter().enumerate() {
+                if matches_value(&tense, &form.tense, false)
+                    && matches_value(&person, &form.person, true)
+                    && matches_value(&number, &form.number, false)
+                    && matches_value(&gender, &form.gender, true)
+                {
+                    selected.push((MatchedForm::new(index, kind), form.clone()));
+                }
+            }
+        }
+        selected
+    }
+
+    /// These features with every feature set on `target` replacing the one here. Person and
+    /// gender "all" are dropped so that they do not rule out the forms of another tense, and
+    /// the binyan is dropped when the voice changes.
+    pub fn overridden_by(&self, target: &Features) -> Features {
+        let specific = |value: &Option<String>| value.clone().filter(|v| v != "all");
+        let voice_changes = target.passive.is_some() && target.passive != self.passive;
+        Features {
+            tense: target.tense.clone().or_else(|| self.tense.clone()),
+            person: target.person.clone().or_else(|| specific(&self.person)),
+            number: target.number.clone().or_else(|| self.number.clone()),
+            gender: target.gender.clone().or_else(|| specific(&self.gender)),
+            binyan: target
+                .binyan
+                .clone()
+                .or_else(|| self.binyan.clone().filter(|_| !voice_changes)),
+            passive: target.passive.or(self.passive),
+        }
+    }
+
+    /// Features of the form a `MatchedForm` points to, `None` if it points nowhere.
+    pub fn of(word: &WordData, matched: &MatchedForm) -> Option<Features> {
+        let passive = matched.kind == 2;
+        let binyan = if passive {
+            word.passive_binyan.clone()
+        } else {
+            Some(word.binyan.clone())
+        };
+        if matched.kind == 0 {
+            return Some(Features {
+                tense: Some("infinitive".to_string()),
+                binyan,
+                passive: Some(false),
+                ..Features::default()
+            });
+        }
+        let form = word.matched_form(matched)?;
+        Some(Features {
+            tense: Some(form.tense.clone()),
+            person: Some(form.person.clone()),
+            number: Some(form.number.clone()),
+            gender: Some(form.gender.clone()),
+            binyan,
+            passive: Some(passive),
+        })
+    }
+
+    /// Parses a Universal Dependencies FEATS string such as
+    /// `Gender=Fem|HebBinyan=PAAL|Number=Sing|Person=3|Tense=Past|VerbForm=Fin|Voice=Act`.
+    /// Positions in errors are character offsets of the offending feature.
+    pub fn parse_ud(feats: &str) -> Result<Features, ParseError> {
+        let mut features = Features::default();
+        let mut verb_form = None;
+        let mut mood = None;
+        let mut position = 0;
+        for feat in feats.split('|') {
+            let error = |message: String| ParseError { position, message };
+            let (name, value) = feat
+                .split_once('=')
+                .ok_or_else(|| error(format!("expected Name=Value, got '{}'", feat)))?;
+            let invalid = || error(format!("invalid value '{}' for {}", value, name));
+            match name {
+                "VerbForm" => verb_form = Some((value, position)),
+                "Mood" => mood = Some((value, position)),
+                "Tense" => {
+                    let tense = match value {
+                        "Past" => "past",
+                        "Pres" => "present",
+                        "Fut" => "future",
+                        _ => return Err(invalid()),
+                    };
+                    features.tense = Some(tense.to_string());
+                }
+                "Person" => {
+                    let person = if value == "1,2,3" {
+                        Some("all".to_string())
+                    } else {
+                        canonical_person(value)
+                    };
+                    features.person = Some(person.ok_or_else(invalid)?);
+                }
+                "Number" => {
+                    features.number = Some(canonical_number(value).ok_or_else(invalid)?);
+                }
+                "Gender" => {
+                    let gender = match value {
+                        "Masc" => Some("m".to_string()),
+                        "Fem" => Some("f".to_string()),
+                        "Fem,Masc" => Some("all".to_string()),
+                        _ => canonical_gender(value),
+                    };
+                    features.gender = Some(gender.ok_or_else(invalid)?);
+                }
+                "HebBinyan" => {
+                    features.binyan = Some(canonical_binyan(value).ok_or_else(invalid)?);
+                }
+                "Voice" => {
+                    let passive = match value {
+                        "Act" | "Mid" => false,
+                        "Pass" => true,
+                        _ => return Err(invalid()),
+                    };
+                    features.passive = Some(passive);
+                }
+                _ => return Err(error(format!("unsupported feature '{}'", name))),
+            }
+            position += feat.chars().count() + 1;
+        }
+        match (verb_form, mood) {
+            (Some(("Inf", _)), _) => features.tense = Some("infinitive".to_string()),
+            (_, Some(("Imp", _))) => features.tense = Some("imperative".to_string()),
+            (Some(("Part", _)), _) => {
+                features.tense = Some("present".to_string());
+                features.person.get_or_insert_with(|| "all".to_string());
+            }
+            (Some(("Fin", _)), _) | (None, None) => {}
+            (Some((other, position)), _) => {
+                return Err(ParseError {
+                    position,
+                    message: format!("unsupported VerbForm '{}'", other),
+                })
+            }
+            (None, Some((other, position))) => {
+                return Err(ParseError {
+                    position,
+                    message: format!("unsupported Mood '{}'", other),
+                })
+            }
+        }
+        Ok(features)
+    }
+}
+
+#[cfg_attr(feature = "wasm-support", wasm_bindgen)]
+impl Features {
+    /// Universal Dependencies FEATS string, features sorted alphabetically as UD requires.
+    /// Present forms are participles (`VerbForm=Part`) without person, imperatives are
+    /// `Mood=Imp`, and "all" becomes `Gender=Fem,Masc` or `Person=1,2,3`.
+    pub fn to_ud(&self) -> String {
+        let mut feats: Vec<(&str, String)> = Vec::new();
+        let tense = self.tense.as_deref().unwrap_or("");
+        if let Some(gender) = self.gender.as_deref() {
+            let value = match gender {
+                "m" => "Masc",
+                "f" => "Fem",
+                _ => "Fem,Masc",
+            };
+            feats.push(("Gender", value.to_string()));
+        }
+        if let Some(binyan) = self.binyan.as_deref() {
+            let value: String = binyan.chars().filter(|c| c.is_ascii_alphabetic()).collect();
+            feats.push(("HebBinyan", value.to_uppercase()));
+        }
+        if tense == "imperative" {
+            feats.push(("Mood", "Imp".to_string()));
+        }
+        if let Some(number) = self.number.as_deref() {
+            let value = if number == "plural" { "Plur" } else { "Sing" };
+            feats.push(("Number", value.to_string()));
+        }
+        if let Some(person) = self.person.as_deref().filter(|_| tense != "present") {
+            let value = match person {
+                "all" => "1,2,3".to_string(),
+                _ => person.trim_end_matches(char::is_alphabetic).to_string(),
+            };
+            feats.push(("Person", value));
+        }
+        match tense {
+            "past" => feats.push(("Tense", "Past".to_string())),
+            "future" => feats.push(("Tense", "Fut".to_string())),
+            _ => {}
+        }
+        let verb_form = match tense {
+            "infinitive" => Some("Inf"),
+            "present" => Some("Part"),
+            "" => None,
+            _ => Some("Fin"),
+        };
+        if let Some(verb_form) = verb_form {
+            feats.push(("VerbForm", verb_form.to_string()));
+        }
+        if let Some(passive) = self.passive {
+            feats.push(("Voice", if passive { "Pass" } else { "Act" }.to_string()));
+        }
+        feats
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<String>>()
+            .join("|")
+    }
+}
+
+#[cfg_attr(feature = "wasm-support", wasm_bindgen)]
+impl SearchResult {
+    /// Universal Dependencies features of every matching form, in the same order.
+    pub fn ud_features(&self) -> Vec<String> {
+        self.matching_forms
+            .iter()
+            .filter_map(|m| Features::of(&self.word, m))
+            .map(|f| f.to_ud())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::features::{Features, InflectError};
+    use crate::test_data::test_index;
+
+    #[test]
+    fn format_ud_features() {
+        let index = test_index();
+        let cases = [
+            (
+                "כתבה",
+                "Gender=Fem|HebBinyan=PAAL|Number=Sing|Person=3|Tense=Past|VerbForm=Fin|Voice=Act",
+            ),
+            (
+                "כתבו",
+                "Gender=Fem,Masc|HebBinyan=PAAL|Number=Plur|Person=3|Tense=Past|VerbForm=Fin|Voice=Act",
+            ),
+            (
+                "מסודרות",
+                "Gender=Fem|HebBinyan=PUAL|Number=Plur|VerbForm=Part|Voice=Pass",
+            ),
+            ("לסדר", "HebBinyan=PIEL|VerbForm=Inf|Voice=Act"),
+            (
+                "כתבי",
+                "Gender=Fem|HebBinyan=PAAL|Mood=Imp|Number=Sing|Person=2|VerbForm=Fin|Voice=Act",
+            ),
+        ];
+        for (form, expected) in cases {
+            let results = index.get(form);
+            let feats = results[0].ud_features();
+            assert_eq!(feats[0], expected, "wrong features for {}", form);
+        }
+    }
+
+    #[test]
+    fn parse_ud_features_round_trip() {
+        let index = test_index();
+        for form in ["כתבה", "כתבו", "מסודרות", "לסדר", "כתבי", "אכתוב"]
+        {
+            let result = &index.get(form)[0];
+            for matched in &result.matching_forms {
+                let features = Features::of(&result.word, matched).unwrap();
+                assert_eq!(Features::parse_ud(&features.to_ud()).unwrap(), features);
+            }
+        }
+        let err = Features::parse_ud("Tense=Past|Number=Dual").unwrap_err();
+        assert_eq!(err.position, 11);
+        assert!(Features::parse_ud("Case=Acc").is_err());
+        assert!(Features::parse_ud("Tense").is_err());
+        let err = Features::parse_ud("Number=Sing|VerbForm=Ger|Voice=Act").unwrap_err();
+        assert_eq!(err.position, 12);
+        assert_eq!(err.message, "unsupported VerbForm 'Ger'");
+        let err = Features::parse_ud("Gender=Masc|Mood=Sub").unwrap_err();
+        assert_eq!(err.position, 12);
+    }
+
+    #[test]
+    fn inflect_by_features() {
+        let index = test_index();
+        let features = Features {
+            tense: Some("future".to_string()),
+            person: Some("2nd".to_string()),
+            number: Some("plural".to_string()),
+            gender: Some("f".to_string()),
+            ..Features::default()
+        };
+        let forms = index.inflect("לכתוב", &features).unwrap();
+        assert_eq!(forms.len(), 1);
+        assert_eq!(forms[0].form_vowelled.as_deref(), Some("תִּכְתֹּבְנָה"));
+
+        let past = Features {
+            tense: Some("past".to_string()),
+            ..Features::default()
+        };
+        assert_eq!(index.inflect("1420-lesader", &past).unwrap().len(), 18);
+        let passive_past = Features {
+            passive: Some(true),
+            ..past
+        };
+        let forms = index.inflect("1420-lesader", &passive_past).unwrap();
+        assert_eq!(forms.len(), 9);
+        assert_eq!(forms[0].form, "סודרתי");
+        // every form, the infinitive included
+        let forms = index.inflect("לסדר", &Features::default()).unwrap();
+        assert_eq!(forms.len(), 51);
+        assert_eq!(forms[0].tense, "infinitive");
+
+        let abbreviated = Features {
+            tense: Some("Future".to_string()),
+            person: Some("2".to_string()),
+            number: Some("pl".to_string()),
+            gender: Some("fem".to_string()),
+            binyan: Some("paal".to_string()),
+            ..Features::default()
+        };
+        let forms = index.inflect("לכתוב", &abbreviated).unwrap();
+        assert_eq!(forms.len(), 1);
+        assert_eq!(forms[0].form, "תכתובנה");
+        let infinitive = Features {
+            tense: Some("inf".to_string()),
+            ..Features::default()
+        };
+        assert_eq!(
+            index.inflect("לכתוב", &infinitive).unwrap()[0].form,
+            "לכתוב"
+        );
+
+        let imperative_1st = Features {
+            tense: Some("imperative".to_string()),
+            person: Some("1st".to_string()),
+            ..Features::default()
+        };
+        assert_eq!(
+            index.inflect("לכתוב", &imperative_1st).err(),
+            Some(InflectError::NoSuchForm {
+                word: "לכתוב".to_string(),
+                features: "Mood=Imp|Person=1|VerbForm=Fin".to_string(),
+            })
+        );
+        assert_eq!(
+            index.inflect("לאכול", &Features::default()).err(),
+            Some(InflectError::UnknownWord("לאכול".to_string()))
+        );
+    }
+
+    #[test]
+    fn transform_forms() {
+        let index = test_index();
+        let future = Features {
+            tense: Some("future".to_string()),
+            ..Features::default()
+        };
+        let result = index.transform("כתבתי", &future).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].source.transcription, "katavti");
+        let forms: Vec<&str> = result[0].forms.iter().map(|f| f.form.as_str()).collect();
+        assert_eq!(forms, vec!["אכתוב"]);
+
+        // "כתבת" is both masculine and feminine
+        let result = index.transform("כתבת", &future).unwrap();
+        let forms: Vec<&str> = result.iter().map(|t| t.forms[0].form.as_str()).collect();
+        assert_eq!(forms, vec!["תכתוב", "תכתבי"]);
+
+        let passive = Features {
+            passive: Some(true),
+            ..Features::default()
+        };
+        let result = index.transform("סידרה", &passive).unwrap();
+        assert_eq!(result[0].forms.len(), 1);
+        assert_eq!(result[0].forms[0].form, "סודרה");
+
+        // present forms go to every person of the past
+        let past = Features {
+            tense: Some("past".to_string()),
+            ..Features::default()
+        };
+        let result = index.transform("כותבת", &past).unwrap();
+        let forms: Vec<&str> = result[0].forms.iter().map(|f| f.form.as_str()).collect();
+        assert_eq!(forms, vec!["כתבתי", "כתבת", "כתבה"]);
+
+        let imperative = Features {
+            tense: Some("imperative".to_string()),
+            ..Features::default()
+        };
+        assert!(matches!(
+            index.transform("כתבתי", &imperative),
+            Err(InflectError::NoSuchForm { .. })
+        ));
+        assert!(matches!(
+            index.transform("אתמול", &future),
+            Err(InflectError::UnknownWord(_))
+        ));
+    }
+}
@@ -0,0 +1,281 @@
+use std::cmp::Ordering;
+
+#[cfg(feature = "wasm-support")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::conjugation::{position, GENDERS, NUMBERS, PERSONS, TENSES};
+use crate::query::{
+    canonical_gender, canonical_number, canonical_person, canonical_state, canonical_tense,
+};
+use crate::word_dto::{WordData, WordForm};
+
+/// The requested cell with its values made canonical, so "3", "sg" or "fem" are accepted.
+struct Cell {
+    tense: String,
+    person: String,
+    number: String,
+    gender: String,
+}
+
+impl Cell {
+    fn new(tense: &str, person: &str, number: &str, gender: &str) -> Option<Cell> {
+        Some(Cell {
+            tense: canonical_tense(tense)?,
+            person: canonical_person(person)?,
+            number: canonical_number(number)?,
+            gender: canonical_gender(gender)?,
+        })
+    }
+
+    /// A form marked "all" for person or gender fills the cell of every person or gender.
+    fn holds(&self, form: &WordForm) -> bool {
+        form.tense == self.tense
+            && (form.person == self.person || form.person == "all")
+            && form.number == self.number
+            && (form.gender == self.gender || form.gender == "all")
+    }
+}
+
+fn find_form(
+    forms: &[WordForm],
+    tense: &str,
+    person: &str,
+    number: &str,
+    gender: &str,
+) -> Option<WordForm> {
+    let cell = Cell::new(tense, person, number, gender)?;
+    forms.iter().find(|f| cell.holds(f)).cloned()
+}
+
+fn sorted(forms: &[WordForm]) -> Vec<WordForm> {
+    let mut forms = forms.to_vec();
+    forms.sort_by(WordForm::canonical_cmp);
+    forms
+}
+
+impl WordForm {
+    /// The canonical order of forms: by tense as in the conjugation tables (present, past,
+    /// future, imperative), then person, number and gender, masculine before feminine.
+    pub fn canonical_cmp(&self, other: &WordForm) -> Ordering {
+        let key = |f: &WordForm| {
+            (
+                position(&TENSES, &f.tense),
+                position(&PERSONS, &f.person),
+                position(&NUMBERS, &f.number),
+                position(&GENDERS, &f.gender),
+            )
+        };
+        key(self).cmp(&key(other))
+    }
+}
+
+#[cfg_attr(feature = "wasm-support", wasm_bindgen)]
+impl WordData {
+    /// The active form in the given cell, e.g. `form("past", "3rd", "singular", "f")`.
+    /// Values may be abbreviated as in queries ("3", "sg", "fem"); for the present tense any
+    /// person matches. Tense "infinitive" gives the infinitive of a verb whatever the other
+    /// values, and nothing for other words.
+    pub fn form(&self, tense: &str, person: &str, number: &str, gender: &str) -> Option<WordForm> {
+        if canonical_tense(tense).as_deref() == Some("infinitive") {
+            return self.is_verb().then(|| self.infinitive_form());
+        }
+        find_form(&self.forms, tense, person, number, gender)
+    }
+
+    /// Like `form`, in the passive binyan. None for verbs without a passive.
+    pub fn passive_form(
+        &self,
+        tense: &str,
+        person: &str,
+        number: &str,
+        gender: &str,
+    ) -> Option<WordForm> {
+        find_form(self.passive.as_ref()?, tense, person, number, gender)
+    }
+
+    /// The active forms of a tense in canonical order, empty for an unknown tense.
+    pub fn forms_by_tense(&self, tense: &str) -> Vec<WordForm> {
+        let Some(tense) = canonical_tense(tense) else {
+            return vec![];
+        };
+        if tense == "infinitive" {
+            return self.infinitives.clone();
+        }
+        let forms: Vec<WordForm> = self
+            .forms
+            .iter()
+            .filter(|f| f.tense == tense)
+            .cloned()
+            .collect();
+        sorted(&forms)
+    }
+
+    /// The active forms in canonical order, see `WordForm::canonical_cmp`.
+    pub fn sorted_forms(&self) -> Vec<WordForm> {
+        sorted(&self.forms)
+    }
+
+    /// The passive forms in canonical order, empty for verbs without a passive.
+    pub fn sorted_passive_forms(&self) -> Vec<WordForm> {
+        self.passive.as_deref().map(sorted).unwrap_or_default()
+    }
+
+    /// The noun form of a number in the "absolute" or "construct" state, e.g. "ספרי" for
+    /// `noun_form("plural", "construct")` of "ספר".
+    pub fn noun_form(&self, number: &str, state: &str) -> Option<WordForm> {
+        let number = canonical_number(number)?;
+        let state = canonical_state(state)?;
+        self.forms
+            .iter()
+            .find(|f| f.number == number && f.state.as_deref() == Some(state.as_str()))
+            .cloned()
+    }
+
+    /// The adjective form agreeing with a number and gender, in the "absolute" or "construct"
+    /// state, e.g. "סדירות" for `adjective_form("plural", "f", "absolute")` of "סדיר".
+    pub fn adjective_form(&self, number: &str, gender: &str, state: &str) -> Option<WordForm> {
+        let number = canonical_number(number)?;
+        let gender = canonical_gender(gender)?;
+        let state = canonical_state(state)?;
+        self.forms
+            .iter()
+            .find(|f| {
+                f.number == number
+                    && f.gender == gender
+                    && f.state.as_deref() == Some(state.as_str())
+            })
+            .cloned()
+    }
+
+    /// The preposition with the pronominal suffix of the given person, number and gender, e.g.
+    /// "אותך" for `suffixed_form("2nd", "singular", "f")` of "את".
+    pub fn suffixed_form(&self, person: &str, number: &str, gender: &str) -> Option<WordForm> {
+        if self.part_of_speech != "preposition" {
+            return None;
+        }
+        // preposition forms have no tense
+        let cell = Cell {
+            tense: String::new(),
+            person: canonical_person(person)?,
+            number: canonical_number(number)?,
+            gender: canonical_gender(gender)?,
+        };
+        self.forms.iter().find(|f| cell.holds(f)).cloned()
+    }
+
+    /// The noun form of a number with the possessive suffix of the given person, number and
+    /// gender, e.g. "ספריו" for `possessive_form("plural", "3rd", "singular", "m")` of "ספר".
+    /// A possessor marked "all" (as in 1st person) matches any gender.
+    pub fn possessive_form(
+        &self,
+        number: &str,
+        person: &str,
+        possessor_number: &str,
+        possessor_gender: &str,
+    ) -> Option<WordForm> {
+        let number = canonical_number(number)?;
+        let person = canonical_person(person)?;
+        let possessor_number = canonical_number(possessor_number)?;
+        let possessor_gender = canonical_gender(possessor_gender)?;
+        self.forms
+            .iter()
+            .find(|f| {
+                f.number == number
+                    && f.possessor.as_ref().is_some_and(|p| {
+                        p.person == person
+                            && p.number == possessor_number
+                            && (p.gender == possessor_gender || p.gender == "all")
+                    })
+            })
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_data::{et, lesader, lichtov, sadir, sefer};
+
+    #[test]
+    fn look_up_cells() {
+        let word = lichtov();
+        let form = word.form("past", "3rd", "singular", "f").unwrap();
+        assert_eq!(form.form, "כתבה");
+        assert_eq!(word.form("past", "3", "sg", "fem").unwrap().form, "כתבה");
+        assert_eq!(
+            word.form("present", "1st", "plural", "m").unwrap().form,
+            "כותבים"
+        );
+        assert_eq!(word.form("infinitive", "", "", "").unwrap().form, "לכתוב");
+        assert!(word.form("past", "4th", "singular", "m").is_none());
+        assert!(word.passive_form("past", "3rd", "singular", "m").is_none());
+        let passive = lesader()
+            .passive_form("past", "3rd", "singular", "m")
+            .unwrap();
+        assert_eq!(passive.form, "סודר");
+    }
+
+    #[test]
+    fn forms_in_canonical_order() {
+        let word = lichtov();
+        let present: Vec<String> = word
+            .forms_by_tense("present")
+            .into_iter()
+            .map(|f| f.form)
+            .collect();
+        assert_eq!(present, ["כותב", "כותבת", "כותבים", "כותבות"]);
+        let sorted = word.sorted_forms();
+        assert_eq!(sorted.len(), word.forms.len());
+        assert_eq!(sorted[0].tense, "present");
+        assert_eq!(sorted.last().unwrap().tense, "imperative");
+        assert_eq!(lesader().sorted_passive_forms().len(), 23);
+    }
+
+    #[test]
+    fn look_up_adjective_forms() {
+        let word = sadir();
+        assert_eq!(
+            word.adjective_form("sg", "f", "absolute").unwrap().form,
+            "סדירה"
+        );
+        let construct = word.adjective_form("plural", "m", "construct").unwrap();
+        assert_eq!(construct.form, "סדירי");
+        assert_eq!(construct.transcription, "sdirei");
+        assert!(word.adjective_form("plural", "all", "absolute").is_none());
+    }
+
+    #[test]
+    fn look_up_suffixed_prepositions() {
+        let word = et();
+        assert_eq!(
+            word.suffixed_form("2nd", "singular", "f")
+                .unwrap()
+                .transcription,
+            "otach"
+        );
+        assert_eq!(word.suffixed_form("1", "sg", "m").unwrap().form, "אותי");
+        assert_eq!(
+            word.suffixed_form("2nd", "plural", "m").unwrap().form,
+            "אתכם"
+        );
+        assert!(lichtov().suffixed_form("1st", "singular", "m").is_none());
+    }
+
+    #[test]
+    fn look_up_noun_forms() {
+        let word = sefer();
+        assert_eq!(word.noun_form("pl", "construct").unwrap().form, "ספרי");
+        assert_eq!(word.noun_form("plural", "absolute").unwrap().form, "ספרים");
+        let possessive = word.possessive_form("plural", "3", "sg", "m").unwrap();
+        assert_eq!(possessive.form, "ספריו");
+        assert_eq!(possessive.transcription, "sfarav");
+        assert_eq!(
+            word.possessive_form("singular", "1st", "plural", "f")
+                .unwrap()
+                .form,
+            "ספרנו"
+        );
+        assert!(word.form("past", "3rd", "singular", "m").is_none());
+        assert!(word.form("infinitive", "", "", "").is_none());
+        assert!(et().form("infinitive", "", "", "").is_none());
+    }
+}
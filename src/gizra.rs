@@ -0,0 +1,135 @@
+use crate::util::normalize_root;
+
+pub(crate) const PE_NUN: &str = "pe_nun";
+pub(crate) const PE_YOD: &str = "pe_yod";
+pub(crate) const PE_GUTTURAL: &str = "pe_guttural";
+pub(crate) const AYIN_VAV: &str = "ayin_vav";
+pub(crate) const AYIN_YOD: &str = "ayin_yod";
+pub(crate) const AYIN_GUTTURAL: &str = "ayin_guttural";
+pub(crate) const LAMED_HE: &str = "lamed_he";
+pub(crate) const LAMED_ALEF: &str = "lamed_alef";
+pub(crate) const LAMED_GUTTURAL: &str = "lamed_guttural";
+pub(crate) const KFULIM: &str = "kfulim";
+pub(crate) const MERUBAIM: &str = "merubaim";
+pub(crate) const SHLEMIM: &str = "shlemim";
+
+fn is_guttural(c: char) -> bool {
+    matches!(c, 'א' | 'ה' | 'ח' | 'ע')
+}
+
+/// Classifies a root into its gizrot (weak root classes). A root may belong to several
+/// classes, e.g. נ-ש-א is both pe-nun and lamed-alef; roots without any weak letter are
+/// "shlemim". Words without a root, such as prepositions, have no gizrot.
+pub(crate) fn classify_root(root: &str) -> Vec<String> {
+    let letters: Vec<char> = normalize_root(root).chars().collect();
+    let mut classes = Vec::new();
+    match letters.as_slice() {
+        [first, second, third] => {
+            // ל-ק-ח conjugates as pe-nun and ה-ל-כ as pe-yod
+            if *first == 'נ' || letters == ['ל', 'ק', 'ח'] {
+                classes.push(PE_NUN);
+            }
+            if *first == 'י' || letters == ['ה', 'ל', 'כ'] {
+                classes.push(PE_YOD);
+            }
+            if is_guttural(*first) {
+                classes.push(PE_GUTTURAL);
+            }
+            match second {
+                'ו' => classes.push(AYIN_VAV),
+                'י' => classes.push(AYIN_YOD),
+                c if is_guttural(*c) || *c == 'ר' => classes.push(AYIN_GUTTURAL),
+                _ => {}
+            }
+            match third {
+                'ה' => classes.push(LAMED_HE),
+                'א' => classes.push(LAMED_ALEF),
+                'ח' | 'ע' => classes.push(LAMED_GUTTURAL),
+                _ => {}
+            }
+            if second == third {
+                classes.push(KFULIM);
+            }
+        }
+        [_, _, _, _] => classes.push(MERUBAIM),
+        _ => {}
+    }
+    if classes.is_empty() && !letters.is_empty() {
+        classes.push(SHLEMIM);
+    }
+    classes.into_iter().map(String::from).collect()
+}
+
+/// Accepts the class ids as well as the usual Hebrew abbreviations: "ל״ה", "ל\"ה", "lamed-he".
+pub(crate) fn canonical_gizra(val: &str) -> Option<String> {
+    let key: String = val
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter(|c| !matches!(c, '"' | '״' | '\'' | '׳' | '-' | '_' | ' '))
+        .collect();
+    let gizra = match key.as_str() {
+        "penun" | "פנ" | "פנון" => PE_NUN,
+        "peyod" | "פי" | "פיוד" => PE_YOD,
+        "peguttural" | "פג" | "פגרונית" => PE_GUTTURAL,
+        "ayinvav" | "עו" | "עוו" => AYIN_VAV,
+        "ayinyod" | "עי" | "עיוד" => AYIN_YOD,
+        "ayinguttural" | "עג" | "עגרונית" => AYIN_GUTTURAL,
+        "lamedhe" | "לה" | "להא" => LAMED_HE,
+        "lamedalef" | "לא" | "לאלף" => LAMED_ALEF,
+        "lamedguttural" | "לג" | "לגרונית" => LAMED_GUTTURAL,
+        "kfulim" | "doubled" | "כפולים" => KFULIM,
+        "merubaim" | "quadriliteral" | "מרובעים" => MERUBAIM,
+        "shlemim" | "regular" | "שלמים" => SHLEMIM,
+        _ => return None,
+    };
+    Some(gizra.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gizra::{canonical_gizra, classify_root};
+    use crate::query::Query;
+    use crate::test_data::test_index;
+
+    #[test]
+    fn classify_roots() {
+        let cases = [
+            ("כ - ת - ב", vec!["shlemim"]),
+            ("נ - פ - ל", vec!["pe_nun"]),
+            ("י - ש - ב", vec!["pe_yod"]),
+            ("ק - ו - ם", vec!["ayin_vav"]),
+            ("ש - י - ר", vec!["ayin_yod"]),
+            ("ק - נ - ה", vec!["lamed_he"]),
+            ("נ - ש - א", vec!["pe_nun", "lamed_alef"]),
+            ("ס - ב - ב", vec!["kfulim"]),
+            ("ש - מ - ע", vec!["lamed_guttural"]),
+            ("ע - ב - ד", vec!["pe_guttural"]),
+            ("ת - ר - ג - ם", vec!["merubaim"]),
+            ("ה - ל - ך", vec!["pe_yod", "pe_guttural"]),
+            ("", vec![]),
+        ];
+        for (root, expected) in cases {
+            assert_eq!(classify_root(root), expected, "wrong class for {}", root);
+        }
+    }
+
+    #[test]
+    fn parse_gizra_names() {
+        assert_eq!(canonical_gizra("ל״ה").as_deref(), Some("lamed_he"));
+        assert_eq!(canonical_gizra("Lamed-He").as_deref(), Some("lamed_he"));
+        assert_eq!(canonical_gizra("פ\"נ").as_deref(), Some("pe_nun"));
+        assert_eq!(canonical_gizra("foo"), None);
+    }
+
+    #[test]
+    fn filter_and_count_by_gizra() {
+        let index = test_index();
+        assert_eq!(index.get("כתבתי")[0].word.gizrot, vec!["shlemim"]);
+        let results = index.query(&Query::new().gizra("shlemim").binyan("piel"));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].word.url_id, "1420-lesader");
+        assert!(index.search("gizra:ל״ה").unwrap().is_empty());
+        assert_eq!(index.gizra_counts().get("shlemim"), Some(&2));
+    }
+}
@@ -0,0 +1,282 @@
+#[cfg(feature = "wasm-support")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::tokenizer::tokenize;
+use crate::util::{display_width, escape_html};
+use crate::word_dto::{Gloss, GlossReading, GlossToken, MatchedForm, SearchResult, WordForm};
+
+fn number_tag(number: &str) -> String {
+    match number {
+        "singular" => "SG".to_string(),
+        "plural" => "PL".to_string(),
+        other => other.to_uppercase(),
+    }
+}
+
+/// Person and gender, unless shared by all, then number as tag parts, e.g. ["3", "F", "SG"].
+/// Features left empty, as on an uninflected lemma, are left out.
+fn agreement_tag(person: &str, number: &str, gender: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    if !person.is_empty() && person != "all" {
+        parts.push(person.trim_end_matches(char::is_alphabetic).to_string());
+    }
+    if !gender.is_empty() && gender != "all" {
+        parts.push(gender.to_uppercase());
+    }
+    if !number.is_empty() {
+        parts.push(number_tag(number));
+    }
+    parts
+}
+
+/// Tag of a noun or adjective form: "N" with the number or "ADJ" with gender and number, then
+/// "CSTR" for the construct state or "POSS" and the possessor, e.g. "N.PL.POSS.3.M.SG" for
+/// "ספריו" and "ADJ.F.SG" for "סדירה".
+fn nominal_tag(form: &WordForm, part_of_speech: &str) -> String {
+    let mut parts = if part_of_speech == "adjective" {
+        vec!["ADJ".to_string(), form.gender.to_uppercase()]
+    } else {
+        vec!["N".to_string()]
+    };
+    parts.push(number_tag(&form.number));
+    if form.state.as_deref() == Some("construct") {
+        parts.push("CSTR".to_string());
+    }
+    if let Some(possessor) = &form.possessor {
+        parts.push("POSS".to_string());
+        parts.extend(agreement_tag(
+            &possessor.person,
+            &possessor.number,
+            &possessor.gender,
+        ));
+    }
+    parts.join(".")
+}
+
+/// Compact grammatical tag of a form such as "PAST.3.F.SG", persons and genders shared by
+/// all ("all") are left out; passive forms end in ".PASS" and the infinitive is "INF".
+/// Noun and adjective forms are tagged by `nominal_tag`, inflected prepositions by their
+/// suffix as in "PREP.2.F.SG" and the bare preposition as "PREP".
+pub(crate) fn grammar_tag(form: Option<&WordForm>, kind: usize, part_of_speech: &str) -> String {
+    let Some(form) = form.filter(|f| f.tense != "infinitive") else {
+        return "INF".to_string();
+    };
+    if part_of_speech == "preposition" {
+        let mut parts = vec!["PREP".to_string()];
+        parts.extend(agreement_tag(&form.person, &form.number, &form.gender));
+        return parts.join(".");
+    }
+    if part_of_speech != "verb" {
+        return nominal_tag(form, part_of_speech);
+    }
+    let mut parts = vec![match form.tense.as_str() {
+        "past" => "PAST".to_string(),
+        "present" => "PRES".to_string(),
+        "future" => "FUT".to_string(),
+        "imperative" => "IMP".to_string(),
+        other => other.to_uppercase(),
+    }];
+    parts.extend(agreement_tag(&form.person, &form.number, &form.gender));
+    if kind == 2 {
+        parts.push("PASS".to_string());
+    }
+    parts.join(".")
+}
+
+fn readings(results: &[SearchResult]) -> Vec<GlossReading> {
+    results
+        .iter()
+        .flat_map(|result| {
+            result.matching_forms.iter().map(|matched: &MatchedForm| {
+                let word = &result.word;
+                let form = word.matched_form(matched);
+                GlossReading {
+                    url_id: word.url_id.clone(),
+                    lemma: word.word.clone(),
+                    form: form.map_or(word.word.clone(), |f| f.form.clone()),
+                    transcription: form
+                        .map_or(word.transcription.clone(), |f| f.transcription.clone()),
+                    meaning: form.map_or(word.word_en.clone(), |f| f.meaning.clone()),
+                    tag: grammar_tag(form, matched.kind, &word.part_of_speech),
+                }
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn build_gloss(text: &str, lookup: impl Fn(&str) -> Vec<SearchResult>) -> Gloss {
+    let tokens = tokenize(text);
+    let leading = text[..tokens.first().map_or(text.len(), |t| t.byte_start)].to_string();
+    let gloss_tokens = tokens
+        .iter()
+        .enumerate()
+        .map(|(i, token)| {
+            let next_start = tokens.get(i + 1).map_or(text.len(), |t| t.byte_start);
+            GlossToken {
+                token: token.text.to_string(),
+                trailing: text[token.byte_end()..next_start].to_string(),
+                readings: readings(&lookup(token.text)),
+            }
+        })
+        .collect();
+    Gloss {
+        leading,
+        tokens: gloss_tokens,
+    }
+}
+
+fn join_readings(token: &GlossToken, field: impl Fn(&GlossReading) -> &str) -> String {
+    let mut values: Vec<&str> = Vec::new();
+    for reading in &token.readings {
+        let value = field(reading);
+        if !values.contains(&value) {
+            values.push(value);
+        }
+    }
+    values.join(" | ")
+}
+
+#[cfg_attr(feature = "wasm-support", wasm_bindgen)]
+impl Gloss {
+    /// Plain-text interlinear gloss: one column per word with the word, transcription, lemma,
+    /// meaning and tag stacked under each other. Readings of ambiguous words are separated by
+    /// " | ", unknown words only fill the first line.
+    pub fn to_text(&self) -> String {
+        let columns: Vec<[String; 5]> = self
+            .tokens
+            .iter()
+            .map(|token| {
+                [
+                    format!("{}{}", token.token, token.trailing.trim()),
+                    join_readings(token, |r| &r.transcription),
+                    join_readings(token, |r| &r.lemma),
+                    join_readings(token, |r| &r.meaning),
+                    join_readings(token, |r| &r.tag),
+                ]
+            })
+            .collect();
+        let widths: Vec<usize> = columns
+            .iter()
+            .map(|column| column.iter().map(|v| display_width(v)).max().unwrap_or(0))
+            .collect();
+        let mut lines = Vec::new();
+        for row in 0..5 {
+            let mut line = String::new();
+            for (column, width) in columns.iter().zip(&widths) {
+                let value = &column[row];
+                line.push_str(value);
+                line.push_str(&" ".repeat(width - display_width(value) + 2));
+            }
+            lines.push(line.trim_end().to_string());
+        }
+        lines.join("\n")
+    }
+
+    /// Right-to-left HTML gloss, every word is a `gloss-token` span holding one
+    /// `gloss-reading` per reading; unknown words get the `gloss-unknown` class.
+    pub fn to_html(&self) -> String {
+        let mut html = String::from("<div class=\"gloss\" dir=\"rtl\" lang=\"he\">");
+        html.push_str(&escape_html(&self.leading));
+        for token in &self.tokens {
+            let class = if token.readings.is_empty() {
+                "gloss-token gloss-unknown"
+            } else {
+                "gloss-token"
+            };
+            html.push_str(&format!(
+                "<span class=\"{}\"><span class=\"gloss-word\">{}</span>",
+                class,
+                escape_html(&token.token)
+            ));
+            for reading in &token.readings {
+                html.push_str(&format!(
+                    "<span class=\"gloss-reading\">\
+                     <span class=\"gloss-lemma\">{}</span>\
+                     <span class=\"gloss-transcription\" dir=\"ltr\" lang=\"he-Latn\">{}</span>\
+                     <span class=\"gloss-meaning\" dir=\"ltr\" lang=\"en\">{}</span>\
+                     <span class=\"gloss-tag\" dir=\"ltr\">{}</span>\
+                     </span>",
+                    escape_html(&reading.lemma),
+                    escape_html(&reading.transcription),
+                    escape_html(&reading.meaning),
+                    escape_html(&reading.tag)
+                ));
+            }
+            html.push_str("</span>");
+            html.push_str(&escape_html(&token.trailing));
+        }
+        html.push_str("</div>");
+        html
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_data::{et, test_index};
+    use crate::word_index::WordIndex;
+
+    #[test]
+    fn gloss_sentence() {
+        let index = test_index();
+        let gloss = index.gloss("כתבת מכתב, סידרה!");
+        assert_eq!(gloss.tokens.len(), 3);
+        let tags: Vec<&str> = gloss.tokens[0]
+            .readings
+            .iter()
+            .map(|r| r.tag.as_str())
+            .collect();
+        assert_eq!(tags, vec!["PAST.2.M.SG", "PAST.2.F.SG"]);
+        assert!(gloss.tokens[1].readings.is_empty());
+        assert_eq!(gloss.tokens[1].trailing, ", ");
+        assert_eq!(gloss.tokens[2].readings[0].tag, "PAST.3.F.SG");
+        assert_eq!(gloss.tokens[2].readings[0].lemma, "לסדר");
+
+        // columns are as wide as their longest line plus two spaces
+        let expected = [
+            format!("{:<27}{:<7}{}", "כתבת", "מכתב,", "סידרה!"),
+            format!("{:<34}{}", "katavta | katavt", "sidra"),
+            format!("{:<34}{}", "לכתוב", "לסדר"),
+            format!("{:<34}{}", "you wrote", "she arranged"),
+            format!("{:<34}{}", "PAST.2.M.SG | PAST.2.F.SG", "PAST.3.F.SG"),
+        ];
+        assert_eq!(gloss.to_text(), expected.join("\n"));
+        let html = gloss.to_html();
+        assert!(html.starts_with("<div class=\"gloss\" dir=\"rtl\""));
+        assert!(html.contains("<span class=\"gloss-token gloss-unknown\"><span class=\"gloss-word\">מכתב</span></span>, "));
+        assert_eq!(html.matches("class=\"gloss-reading\"").count(), 3);
+    }
+
+    #[test]
+    fn gloss_passive_and_present() {
+        let index = test_index();
+        let gloss = index.gloss("מסודרות");
+        assert_eq!(gloss.tokens[0].readings[0].tag, "PRES.F.PL.PASS");
+    }
+
+    #[test]
+    fn gloss_noun_forms() {
+        let index = test_index();
+        let gloss = index.gloss("ספריו ספרי");
+        assert_eq!(gloss.tokens[0].readings[0].tag, "N.PL.POSS.3.M.SG");
+        let mut tags: Vec<&str> = gloss.tokens[1]
+            .readings
+            .iter()
+            .map(|r| r.tag.as_str())
+            .collect();
+        tags.sort();
+        assert_eq!(tags, vec!["N.PL.CSTR", "N.SG.POSS.1.SG"]);
+        let gloss = index.gloss("סדירת");
+        assert_eq!(gloss.tokens[0].readings[0].tag, "ADJ.F.SG.CSTR");
+    }
+
+    #[test]
+    fn gloss_inflected_prepositions() {
+        let index = WordIndex::build(vec![et()]);
+        let gloss = index.gloss("אותה אותנו");
+        assert_eq!(gloss.tokens[0].readings[0].tag, "PREP.3.F.SG");
+        assert_eq!(gloss.tokens[1].readings[0].tag, "PREP.1.PL");
+        assert_eq!(gloss.tokens[1].readings[0].lemma, "את");
+        let gloss = index.gloss("את");
+        assert_eq!(gloss.tokens[0].readings[0].tag, "PREP");
+    }
+}
@@ -0,0 +1,161 @@
+use crate::conjugator::{male_templates, regular_paradigm, BINYANIM};
+use crate::gizra::{classify_root, SHLEMIM};
+use crate::lemmatizer::vowel_weight;
+use crate::util::{is_hebrew_letter, normalize, unfinal};
+use crate::word_dto::RootGuess;
+
+/// Weight of a guess whose root has no word in the dataset.
+pub(crate) const UNKNOWN_ROOT_WEIGHT: f32 = 0.6;
+/// Weight of a guess whose root belongs to a weak class, the regular paradigm is less
+/// likely to be right about those.
+pub(crate) const WEAK_ROOT_WEIGHT: f32 = 0.8;
+
+/// Root letters when `word` has the shape of `template`: same length, same affix letters,
+/// a root letter wherever the template has a digit.
+fn bind(template: &[char], word: &[char]) -> Option<[char; 3]> {
+    if template.len() != word.len() {
+        return None;
+    }
+    let mut root = [' '; 3];
+    for (t, w) in template.iter().zip(word) {
+        match t.to_digit(10) {
+            Some(slot) if is_hebrew_letter(*w) => root[slot as usize - 1] = *w,
+            Some(_) => return None,
+            None if t == w => {}
+            None => return None,
+        }
+    }
+    Some(root)
+}
+
+/// Share of the word made of affix letters: the more of the word a template explains,
+/// the more telling the match.
+fn specificity(template: &[char]) -> f32 {
+    let affixes = template.iter().filter(|c| !c.is_ascii_digit()).count();
+    affixes as f32 / template.len() as f32
+}
+
+/// Guesses root and binyan of a word by matching it against the regular paradigm of every
+/// binyan: affixes are stripped through the ktiv male templates and each candidate root is
+/// checked by conjugating it again. `url_ids_of_root` links a root ("כ - ת - ב") to the
+/// dataset verbs built on it. Guesses are ordered by descending score.
+pub(crate) fn guess_roots(
+    word: &str,
+    url_ids_of_root: impl Fn(&str) -> Vec<String>,
+) -> Vec<RootGuess> {
+    let letters: Vec<char> = normalize(word).chars().map(unfinal).collect();
+    let mut guesses: Vec<RootGuess> = Vec::new();
+    for binyan in BINYANIM {
+        for template in male_templates(binyan) {
+            let template: Vec<char> = template.chars().collect();
+            let Some(root) = bind(&template, &letters) else {
+                continue;
+            };
+            let root = root
+                .iter()
+                .map(char::to_string)
+                .collect::<Vec<_>>()
+                .join(" - ");
+            let Some(regular) = regular_paradigm(&root, binyan) else {
+                continue;
+            };
+            let matching: Vec<_> = regular
+                .into_iter()
+                .filter(|f| {
+                    f.form_normalized
+                        .chars()
+                        .map(unfinal)
+                        .eq(letters.iter().cloned())
+                })
+                .collect();
+            if matching.is_empty() {
+                continue;
+            }
+            let url_ids = url_ids_of_root(&root);
+            let mut score = 0.5 + 0.5 * specificity(&template);
+            if url_ids.is_empty() {
+                score *= UNKNOWN_ROOT_WEIGHT;
+            }
+            if classify_root(&root) != [SHLEMIM] {
+                score *= WEAK_ROOT_WEIGHT;
+            }
+            score *= matching
+                .iter()
+                .map(|f| vowel_weight(word, f.form_vowelled.as_deref()))
+                .fold(0.0, f32::max);
+            match guesses
+                .iter_mut()
+                .find(|g| g.root == root && g.binyan == binyan)
+            {
+                Some(guess) => {
+                    guess.score = guess.score.max(score);
+                    for form in matching {
+                        if !guess.forms.iter().any(|f| {
+                            (&f.tense, &f.person, &f.number, &f.gender)
+                                == (&form.tense, &form.person, &form.number, &form.gender)
+                        }) {
+                            guess.forms.push(form);
+                        }
+                    }
+                }
+                None => guesses.push(RootGuess {
+                    root,
+                    binyan: binyan.to_string(),
+                    score,
+                    forms: matching,
+                    url_ids,
+                }),
+            }
+        }
+    }
+    // stable sort keeps the binyan order between equal scores
+    guesses.sort_by(|a, b| b.score.total_cmp(&a.score));
+    guesses
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_data::test_index;
+
+    #[test]
+    fn guess_known_root_in_new_binyan() {
+        let index = test_index();
+        assert!(index.get("הכתבנו").is_empty());
+        let guesses = index.analyze_unknown("הכתבנו");
+        assert_eq!(guesses[0].root, "כ - ת - ב");
+        assert_eq!(guesses[0].binyan, "HIF'IL");
+        assert_eq!(guesses[0].url_ids, vec!["927-lichtov"]);
+        assert_eq!(guesses[0].forms[0].tense, "past");
+        assert_eq!(guesses[0].forms[0].transcription, "hichtavnu");
+
+        let guesses = index.analyze_unknown("הסתדרנו");
+        assert_eq!(guesses[0].root, "ס - ד - ר");
+        assert_eq!(guesses[0].binyan, "HITPA'EL");
+        // the adjective "סדיר" shares the root but is not a verb
+        assert_eq!(guesses[0].url_ids, vec!["1420-lesader"]);
+    }
+
+    #[test]
+    fn guess_unknown_root() {
+        let index = test_index();
+        let guesses = index.analyze_unknown("תסגרו");
+        let readings: Vec<(&str, &str)> = guesses
+            .iter()
+            .take(2)
+            .map(|g| (g.root.as_str(), g.binyan.as_str()))
+            .collect();
+        assert_eq!(
+            readings,
+            vec![("ס - ג - ר", "PA'AL"), ("ס - ג - ר", "PI'EL")]
+        );
+        assert!(guesses[0].url_ids.is_empty());
+        assert_eq!(guesses[0].forms.len(), 1);
+        // future 2nd person masculine and 3rd person feminine look the same
+        assert_eq!(index.analyze_unknown("תסגור")[0].forms.len(), 2);
+        assert!(guesses[0].score < 1.0);
+        // niqqud tells PI'EL from PA'AL
+        let guesses = index.analyze_unknown("תְּסַגְּרוּ");
+        assert_eq!(guesses[0].binyan, "PI'EL");
+        assert!(index.analyze_unknown("ok").is_empty());
+    }
+}
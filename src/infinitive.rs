@@ -0,0 +1,244 @@
+use unicode_normalization::UnicodeNormalization;
+
+use crate::conjugator::regular_paradigm;
+use crate::util::is_hebrew_letter;
+use crate::word_dto::{WordData, WordForm};
+
+/// Prepositions the infinitive construct takes besides ל, as (letters, pointed, transcribed).
+/// ב and כ replace the ל and keep its vowel ("בִּכְתֹּב"), כש is put before the bare form.
+const CONSTRUCT_PREFIXES: [(&str, &str, &str); 3] =
+    [("ב", "בּ", "b"), ("כ", "כּ", "k"), ("כש", "כְּשֶׁ", "kshe")];
+
+/// An infinitive form, `form` as the dataset spells it and `form_normalized` without niqqud.
+fn infinitive(
+    form: String,
+    form_normalized: String,
+    vowelled: Option<String>,
+    transcription: String,
+    word: &WordData,
+) -> WordForm {
+    WordForm {
+        tense: "infinitive".to_string(),
+        person: String::new(),
+        number: String::new(),
+        gender: String::new(),
+        form_normalized,
+        form,
+        transcription,
+        meaning: word.word_en.clone(),
+        form_vowelled: vowelled,
+        irregular: false,
+        state: None,
+        possessor: None,
+    }
+}
+
+/// Drops the first letter of a pointed word together with its marks.
+fn strip_first_letter(vowelled: &str) -> String {
+    let mut chars = vowelled.chars().skip(1).peekable();
+    while chars.next_if(|c| !is_hebrew_letter(*c)).is_some() {}
+    chars.collect()
+}
+
+/// The bare infinitive cut off the ל infinitive ("כְתֹּב", "chtov" from "לִכְתֹּב") starts a
+/// word now: a first ב, כ or פ takes a dagesh kal and its hard sound ("כְּתֹּב", "ktov").
+fn word_initial(
+    bare: &str,
+    vowelled: Option<String>,
+    transcription: &str,
+) -> (Option<String>, String) {
+    let hard = match bare.chars().next() {
+        Some('ב') => Some(("v", "b")),
+        Some('כ') => Some(("ch", "k")),
+        Some('פ') => Some(("f", "p")),
+        _ => None,
+    };
+    let Some((soft, hard)) = hard else {
+        return (vowelled, transcription.to_string());
+    };
+    let transcription = match transcription.strip_prefix(soft) {
+        Some(rest) => format!("{}{}", hard, rest),
+        None => transcription.to_string(),
+    };
+    let vowelled = vowelled.map(|v| {
+        let mut chars = v.chars();
+        let first: String = chars.next().into_iter().collect();
+        let rest: String = chars.collect();
+        let marks: String = rest.chars().take_while(|c| !is_hebrew_letter(*c)).collect();
+        if marks.contains('\u{05BC}') {
+            v
+        } else {
+            format!("{}\u{05BC}{}", first, rest).nfd().collect()
+        }
+    });
+    (vowelled, transcription)
+}
+
+/// The infinitive of a verb and its variants as forms: the ל infinitive first, then the
+/// bare infinitive without ל ("כתוב") and the infinitive construct after ב, כ and כש
+/// ("בכתוב", "ככתוב", "כשכתוב"). The niqqud comes from the regular paradigm or from the
+/// imperative spelled like the bare form, and is missing when neither agrees with the word.
+/// The bare form is read like the masculine singular imperative of the dataset, or else of
+/// the regular paradigm, when one is spelled like it.
+/// Words other than verbs have none.
+pub(crate) fn infinitive_forms(word: &WordData) -> Vec<WordForm> {
+    if !word.is_verb() {
+        return vec![];
+    }
+    let paradigm = regular_paradigm(&word.root, &word.binyan).unwrap_or_default();
+    let vowelled = paradigm
+        .iter()
+        .find(|f| f.tense == "infinitive")
+        .filter(|f| f.form_normalized == word.word_normalized)
+        .and_then(|f| f.form_vowelled.clone());
+    let mut forms = vec![infinitive(
+        word.word.clone(),
+        word.word_normalized.clone(),
+        vowelled.clone(),
+        word.transcription.clone(),
+        word,
+    )];
+    let (Some(bare), Some(tail)) = (
+        word.word_normalized.strip_prefix('ל'),
+        word.transcription.strip_prefix('l'),
+    ) else {
+        return forms;
+    };
+    let imperative = word.forms.iter().chain(paradigm.iter()).find(|f| {
+        f.tense == "imperative"
+            && f.number == "singular"
+            && f.gender == "m"
+            && f.form_normalized == bare
+    });
+    let (bare_vowelled, bare_transcription) = match imperative {
+        Some(imperative) => (
+            imperative.form_vowelled.clone(),
+            imperative.transcription.clone(),
+        ),
+        None => word_initial(
+            bare,
+            vowelled.as_deref().map(strip_first_letter),
+            &tail.chars().skip(1).collect::<String>(),
+        ),
+    };
+    forms.push(infinitive(
+        bare.to_string(),
+        bare.to_string(),
+        bare_vowelled.clone(),
+        bare_transcription.clone(),
+        word,
+    ));
+    for (prefix, pointed, latin) in CONSTRUCT_PREFIXES {
+        let (form, form_vowelled, transcription) = if prefix == "כש" {
+            (
+                format!("{}{}", prefix, bare),
+                bare_vowelled.as_ref().map(|v| format!("{}{}", pointed, v)),
+                format!("{}{}", latin, bare_transcription),
+            )
+        } else {
+            (
+                format!("{}{}", prefix, bare),
+                vowelled.as_ref().map(|v| {
+                    // the vowel of ל goes to the new preposition
+                    let marks: String = v
+                        .chars()
+                        .skip(1)
+                        .take_while(|c| !is_hebrew_letter(*c))
+                        .collect();
+                    format!("{}{}{}", pointed, marks, strip_first_letter(v))
+                }),
+                format!("{}{}", latin, tail),
+            )
+        };
+        forms.push(infinitive(
+            form.clone(),
+            form,
+            form_vowelled.map(|v| v.nfd().collect()),
+            transcription,
+            word,
+        ));
+    }
+    forms
+}
+
+#[cfg(test)]
+mod tests {
+    use unicode_normalization::UnicodeNormalization;
+
+    use crate::infinitive::word_initial;
+    use crate::query::Query;
+    use crate::test_data::{lichtov, test_index};
+    use crate::word_index::WordIndex;
+
+    #[test]
+    fn infinitive_variants_are_forms() {
+        let index = test_index();
+        let word = index.get("לכתוב").remove(0).word;
+        let forms: Vec<&str> = word.infinitives.iter().map(|f| f.form.as_str()).collect();
+        assert_eq!(forms, ["לכתוב", "כתוב", "בכתוב", "ככתוב", "כשכתוב"]);
+        assert_eq!(word.infinitives[0].form_vowelled.as_deref(), Some("לִכְתֹּב"));
+        assert_eq!(word.infinitives[2].transcription, "bichtov");
+        for (query, index_in_infinitives) in [("כתוב", 1), ("בכתוב", 2), ("כשכתוב", 4)]
+        {
+            let results = index.get(query);
+            let result = results
+                .iter()
+                .find(|r| r.word.url_id == "927-lichtov")
+                .unwrap();
+            assert!(result
+                .matching_forms
+                .iter()
+                .any(|m| m.kind == 0 && m.index == index_in_infinitives));
+        }
+    }
+
+    #[test]
+    fn pointed_lemma_is_matched_unpointed() {
+        let mut word = lichtov();
+        word.word = "לִכְתּוֹב".to_string();
+        let index = WordIndex::build(vec![word]);
+        let results = index.get("לכתוב");
+        assert_eq!(results.len(), 1);
+        assert!(results[0]
+            .matching_forms
+            .iter()
+            .any(|m| m.kind == 0 && m.index == 0));
+        assert_eq!(results[0].word.infinitives[0].form, "לִכְתּוֹב");
+        assert_eq!(results[0].word.infinitives[0].form_normalized, "לכתוב");
+        let results = index.query(&Query::new().text("לכתוב"));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].matching_forms[0].kind, 0);
+        assert_eq!(
+            index.lemmatize("לכתוב"),
+            vec![("לִכְתּוֹב".to_string(), "927-lichtov".to_string(), 1.0)]
+        );
+    }
+
+    #[test]
+    fn bare_infinitive_takes_the_imperative_spelling() {
+        let word = test_index().get("לסדר").remove(0).word;
+        assert_eq!(word.infinitives[1].form, "סדר");
+        assert_eq!(word.infinitives[1].transcription, "sader");
+
+        // without imperatives in the dataset, the paradigm has one
+        let mut word = lichtov();
+        word.forms.retain(|f| f.tense != "imperative");
+        let index = WordIndex::build(vec![word]);
+        let word = index.get("לכתוב").remove(0).word;
+        assert_eq!(word.infinitives[1].transcription, "ktov");
+        assert_eq!(word.infinitives[1].form_vowelled.as_deref(), Some("כְּתֹב"));
+        assert_eq!(word.infinitives[4].transcription, "kshektov");
+    }
+
+    #[test]
+    fn bare_infinitive_starts_hard() {
+        assert_eq!(
+            word_initial("כתוב", Some("כְתֹּב".to_string()), "chtov"),
+            (Some("כְּתֹּב".nfd().collect()), "ktov".to_string())
+        );
+        assert_eq!(
+            word_initial("שמור", None, "shmor"),
+            (None, "shmor".to_string())
+        );
+    }
+}
@@ -0,0 +1,208 @@
+#[cfg(feature = "wasm-support")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::conjugator::{has_regular_paradigm, regular_paradigm};
+use crate::util::{normalize_root, unfinal};
+use crate::word_dto::{WordData, WordForm};
+
+fn cell_key(prefix: &str, form: &WordForm) -> String {
+    format!(
+        "{}{}.{}.{}.{}",
+        prefix, form.tense, form.person, form.number, form.gender
+    )
+}
+
+/// Spelling of a PA'AL future or imperative with an a stem ("ילבש") instead of the o stem of
+/// the paradigm ("ילבוש"): the ו before the last root letter is dropped.
+fn a_stem(regular: &WordForm, root: &[char]) -> Option<String> {
+    if !matches!(regular.tense.as_str(), "future" | "imperative") || root.len() != 3 {
+        return None;
+    }
+    let chars: Vec<char> = regular.form_normalized.chars().collect();
+    let i = (1..chars.len().saturating_sub(1)).rev().find(|i| {
+        chars[*i] == 'ו' && unfinal(chars[i - 1]) == root[1] && unfinal(chars[i + 1]) == root[2]
+    })?;
+    let mut spelling = chars;
+    spelling.remove(i);
+    Some(spelling.into_iter().collect())
+}
+
+/// A form agrees with the regular one when the spellings match. The niqqud is not compared:
+/// the dataset and the paradigm differ in details such as the dagesh of a begadkefat letter
+/// without the form being irregular. `a_stem` spellings are regular too.
+fn is_regular(form: &WordForm, regular: &WordForm, a_stem: Option<&str>) -> bool {
+    form.form_normalized == regular.form_normalized || a_stem == Some(form.form_normalized.as_str())
+}
+
+/// Flags the forms that differ from the regular paradigm of their binyan and returns the
+/// keys of their cells. Cells the paradigm lacks are left unflagged.
+fn mark_forms(
+    forms: &mut [WordForm],
+    regular: &[WordForm],
+    root: Option<&[char]>,
+    prefix: &str,
+) -> Vec<String> {
+    let mut cells = Vec::new();
+    for form in forms.iter_mut() {
+        let expected = regular.iter().find(|r| {
+            r.tense == form.tense
+                && r.person == form.person
+                && r.number == form.number
+                && r.gender == form.gender
+        });
+        form.irregular = expected.is_some_and(|r| {
+            let a_stem = root.and_then(|root| a_stem(r, root));
+            !is_regular(form, r, a_stem.as_deref())
+        });
+        if form.irregular {
+            cells.push(cell_key(prefix, form));
+        }
+    }
+    cells
+}
+
+/// Compares a word with the regular paradigm of its binyan and root and records the cells
+/// that deviate from it. There is no paradigm for the weak root classes yet, so only roots
+/// `has_regular_paradigm` accepts are compared: weak and guttural roots, irregular ones
+/// such as ה-ל-כ or נ-ת-נ included, and four-letter roots are left unflagged. PA'AL futures
+/// and imperatives may have an a stem.
+pub(crate) fn mark_irregular(word: &mut WordData) {
+    word.irregular_cells.clear();
+    let root: Vec<char> = normalize_root(&word.root).chars().collect();
+    let regular = Some(&word.binyan)
+        .filter(|binyan| has_regular_paradigm(&word.root, &word.gizrot, binyan))
+        .and_then(|binyan| regular_paradigm(&word.root, binyan));
+    if let Some(regular) = regular {
+        let infinitive = regular.iter().find(|r| r.tense == "infinitive");
+        if infinitive.is_some_and(|i| i.form_normalized != word.word_normalized) {
+            word.irregular_cells.push("infinitive".to_string());
+        }
+        let a_stem_root = Some(root.as_slice()).filter(|_| word.binyan == "PA'AL");
+        let cells = mark_forms(&mut word.forms, &regular, a_stem_root, "");
+        word.irregular_cells.extend(cells);
+    }
+    if let (Some(passive), Some(binyan)) = (&mut word.passive, &word.passive_binyan) {
+        let regular = Some(binyan)
+            .filter(|binyan| has_regular_paradigm(&word.root, &word.gizrot, binyan))
+            .and_then(|binyan| regular_paradigm(&word.root, binyan));
+        if let Some(regular) = regular {
+            word.irregular_cells
+                .extend(mark_forms(passive, &regular, None, "passive."));
+        }
+    }
+}
+
+#[cfg_attr(feature = "wasm-support", wasm_bindgen)]
+impl WordData {
+    /// Whether any form deviates from the regular paradigm, see `irregular_cells`. Only
+    /// regular roots are compared (see `mark_irregular`), weak roots are never irregular.
+    pub fn is_irregular(&self) -> bool {
+        !self.irregular_cells.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::conjugator::has_regular_paradigm;
+    use crate::query::Query;
+    use crate::test_data::{lesader, lichtov, test_index};
+    use crate::word_dto::WordData;
+    use crate::word_index::WordIndex;
+
+    #[test]
+    fn regular_verbs_are_not_flagged() {
+        let index = test_index();
+        assert!(index.irregular_verbs().is_empty());
+        let word = index.get("לסדר").remove(0).word;
+        assert!(!word.is_irregular());
+        assert!(word.passive.unwrap().iter().all(|f| !f.irregular));
+    }
+
+    #[test]
+    fn flag_irregular_cells() {
+        let mut word = lichtov();
+        word.forms[10].form = "כתיב".to_string();
+        word.forms[10].form_normalized = "כתיב".to_string();
+        word.forms[10].form_vowelled = Some("כָּתִיב".to_string());
+        word.forms[11].form = "כתיבה".to_string();
+        word.forms[11].form_normalized = "כתיבה".to_string();
+        let index = WordIndex::build(vec![word, lesader()]);
+
+        let irregular = index.irregular_verbs();
+        assert_eq!(irregular.len(), 1);
+        assert_eq!(
+            irregular[0].irregular_cells,
+            vec!["past.3rd.singular.m", "past.3rd.singular.f"]
+        );
+        assert!(irregular[0].forms[10].irregular && !irregular[0].forms[12].irregular);
+
+        let results = index.query(&Query::new().irregular(true).gender("m"));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].matching_forms.len(), 1);
+        assert_eq!(results[0].matching_forms[0].index, 10);
+        let results = index.search("irregular:yes tense:past").unwrap();
+        assert_eq!(results[0].matching_forms.len(), 2);
+        assert!(index.search("irregular:maybe").is_err());
+    }
+
+    #[test]
+    fn niqqud_a_stems_and_weak_roots_are_not_flagged() {
+        let mut word = lichtov();
+        // a different niqqud alone is not enough
+        word.forms[11].form_vowelled = Some("כָּתֵבָה".to_string());
+        // a-stem future and imperative, like "ילבש"
+        for form in word
+            .forms
+            .iter_mut()
+            .filter(|f| f.form == "יכתוב" || f.form == "כתוב")
+        {
+            form.form = form.form.replace('ו', "");
+            form.form_normalized = form.form.clone();
+        }
+        // weak roots are not compared at all
+        let mut weak = lichtov();
+        weak.url_id = "1-weak".to_string();
+        weak.root = "כ - ת - ה".to_string();
+        weak.gizrot = vec![];
+        let index = WordIndex::build(vec![word, weak]);
+        assert!(index.irregular_verbs().is_empty());
+    }
+
+    #[test]
+    fn medial_resh_roots_are_compared() {
+        let mut word = WordData::conjugate("ס-ר-ק", "PA'AL").unwrap();
+        word.url_id = "1-lisrok".to_string();
+        word.synthetic = false;
+        word.forms[10].form = "סריק".to_string();
+        word.forms[10].form_normalized = "סריק".to_string();
+        let index = WordIndex::build(vec![word]);
+        let irregular = index.irregular_verbs();
+        assert_eq!(irregular.len(), 1);
+        assert_eq!(irregular[0].irregular_cells, vec!["past.3rd.singular.m"]);
+    }
+
+    #[test]
+    fn dataset_regular_verbs_are_not_flagged() {
+        let index = WordIndex::init_local();
+        // regular, a-stem future and medial ר verbs, all compared with the paradigm
+        for lemma in ["לכתוב", "ללבוש", "לגדול", "לסרוק"] {
+            let results = index.get(lemma);
+            let word = results
+                .iter()
+                .map(|r| &r.word)
+                .find(|w| w.word_normalized == lemma)
+                .unwrap_or_else(|| panic!("{} is not in the dataset", lemma));
+            assert!(
+                has_regular_paradigm(&word.root, &word.gizrot, &word.binyan),
+                "{} is not compared",
+                lemma
+            );
+            assert!(
+                !word.is_irregular(),
+                "{} flagged in {:?}",
+                lemma,
+                word.irregular_cells
+            );
+        }
+    }
+}
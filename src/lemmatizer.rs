@@ -0,0 +1,127 @@
+use crate::template::prepare_vowelled;
+
+/// Weight of a match found after stripping proclitics ("ו", "ש", "ה", "ב", ...).
+pub(crate) const CLITIC_WEIGHT: f32 = 0.6;
+/// Weight of a match that only agrees once the matres lectionis are ignored ("סדרתי" for
+/// "סידרתי").
+pub(crate) const VARIANT_WEIGHT: f32 = 0.7;
+/// Weight of passive forms, which are rarer than the active ones.
+pub(crate) const PASSIVE_WEIGHT: f32 = 0.9;
+/// Weight of a pointed token whose niqqud differs from the form's vowelled spelling.
+pub(crate) const VOWEL_MISMATCH_WEIGHT: f32 = 0.8;
+
+const CONJUNCTIONS: [&str; 2] = ["", "ו"];
+const SUBORDINATORS: [&str; 4] = ["", "ש", "כש", "מש"];
+const PREPOSITIONS: [&str; 6] = ["", "ה", "ב", "כ", "ל", "מ"];
+
+/// Every way of reading `word` as proclitics followed by a word of at least two letters,
+/// e.g. "וכשכתבתי" gives ("ו", "כשכתבתי") and ("וכש", "כתבתי").
+pub(crate) fn clitic_splits(word: &str) -> Vec<(String, String)> {
+    let mut splits = Vec::new();
+    for conjunction in CONJUNCTIONS {
+        for subordinator in SUBORDINATORS {
+            for preposition in PREPOSITIONS {
+                let prefix = format!("{}{}{}", conjunction, subordinator, preposition);
+                if prefix.is_empty() {
+                    continue;
+                }
+                if let Some(rest) = word.strip_prefix(prefix.as_str()) {
+                    if rest.chars().count() >= 2 && !splits.iter().any(|(p, _)| *p == prefix) {
+                        splits.push((prefix, rest.to_string()));
+                    }
+                }
+            }
+        }
+    }
+    splits
+}
+
+/// Inflection suffixes made of or containing ו and י, longest first.
+const SUFFIXES: [&str; 6] = ["ים", "ות", "תי", "נו", "ו", "י"];
+
+/// Spelling with the matres lectionis ו and י dropped inside the word, so that full and
+/// defective spellings of the same form compare equal. The first letter, the last one and
+/// an inflection suffix (see `SUFFIXES`) are kept: "כתבו" is not spelled like "כתב".
+pub(crate) fn defective_spelling(word: &str) -> String {
+    let chars: Vec<char> = word.chars().collect();
+    let suffix_len = SUFFIXES
+        .iter()
+        .filter(|s| word.ends_with(*s))
+        .map(|s| s.chars().count())
+        .find(|len| chars.len() > len + 1)
+        .unwrap_or(1);
+    let end = chars.len().saturating_sub(suffix_len);
+    chars
+        .iter()
+        .enumerate()
+        .filter(|(i, c)| *i == 0 || *i >= end || !matches!(c, 'ו' | 'י'))
+        .map(|(_, c)| *c)
+        .collect()
+}
+
+/// Weight of a pointed token against the vowelled spelling of the form it matched.
+pub(crate) fn vowel_weight(token: &str, form_vowelled: Option<&str>) -> f32 {
+    match form_vowelled {
+        Some(vowelled) if prepare_vowelled(token) != prepare_vowelled(vowelled) => {
+            VOWEL_MISMATCH_WEIGHT
+        }
+        _ => 1.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lemmatizer::{clitic_splits, defective_spelling};
+    use crate::test_data::{lichtov, test_index};
+    use crate::word_index::WordIndex;
+
+    #[test]
+    fn split_clitics() {
+        let prefixes: Vec<String> = clitic_splits("וכשכתבתי").into_iter().map(|s| s.0).collect();
+        assert_eq!(prefixes, vec!["ו", "וכ", "וכש", "וכשכ"]);
+        assert!(clitic_splits("בי").is_empty());
+        assert_eq!(defective_spelling("סידרתי"), "סדרתי");
+        assert_eq!(defective_spelling("יכתוב"), "יכתב");
+        assert_eq!(defective_spelling("כתבו"), "כתבו");
+        assert_eq!(defective_spelling("כתובים"), "כתבים");
+        assert_eq!(defective_spelling("כתבה"), "כתבה");
+    }
+
+    #[test]
+    fn suffix_letters_are_not_dropped() {
+        // without its plural forms "כתבו" would be read as "כתב" with a full spelling
+        let mut word = lichtov();
+        word.forms.retain(|f| f.form != "כתבו");
+        let index = WordIndex::build(vec![word]);
+        assert!(index.lemmatize("כתבו").is_empty());
+        assert_eq!(index.lemmatize("יכתוב")[0].2, 1.0);
+    }
+
+    #[test]
+    fn lemmatize_forms() {
+        let index = test_index();
+        assert_eq!(
+            index.lemmatize("כתבתי"),
+            vec![("לכתוב".to_string(), "927-lichtov".to_string(), 1.0)]
+        );
+        let lemmas = index.lemmatize("וכשסידרתי");
+        assert_eq!(lemmas.len(), 1);
+        assert_eq!(lemmas[0].1, "1420-lesader");
+        assert_eq!(index.lemmatize("סדרתי")[0].1, "1420-lesader");
+        assert!(index.lemmatize("אתמול").is_empty());
+    }
+
+    #[test]
+    fn lemmatize_scores() {
+        let index = test_index();
+        assert_eq!(index.lemmatize("מסודר")[0].2, 0.9);
+        assert_eq!(index.lemmatize("כָּתַבְתִּי")[0].2, 1.0);
+        assert_eq!(index.lemmatize("כְּתַבְתִּי")[0].2, 0.8);
+        assert_eq!(index.lemmatize("וכתבתי")[0].2, 0.6);
+        assert_eq!(index.lemmatize("סדרתי")[0].2, 0.7);
+        let batch = index.lemmatize_batch(&["כתבתי", "אתמול", "כתבתי"]);
+        assert_eq!(batch.len(), 3);
+        assert!(batch[1].is_empty());
+        assert_eq!(batch[0], batch[2]);
+    }
+}
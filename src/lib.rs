@@ -1,6 +1,25 @@
+mod conjugation;
+pub mod conjugator;
+mod english;
+pub mod features;
+mod form_lookup;
+mod gizra;
+mod gloss;
+mod guess;
+mod infinitive;
+mod irregular;
+mod lemmatizer;
 mod prefix_tree;
 mod proto;
+pub mod query;
+pub mod query_parser;
+mod suffix_index;
+mod template;
+#[cfg(test)]
+mod test_data;
+mod tokenizer;
 mod util;
+mod vocalize;
 pub mod word_dto;
 pub mod word_index;
 
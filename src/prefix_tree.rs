@@ -1,5 +1,7 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
+const MAX_PATTERN_STEPS: usize = 200_000;
+
 #[derive(Debug, Default)]
 struct TrieNode {
     children: HashMap<char, TrieNode>,
@@ -64,6 +66,61 @@ impl Trie {
         }
     }
 
+    pub(crate) fn find_exact(&self, word: &str) -> Vec<String> {
+        match self.starts_with(word) {
+            Some(node) if node.is_word_end => node.ids.iter().flatten().cloned().collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Walks the trie with a wildcard pattern where `?` matches exactly one character and `*`
+    /// any run of characters, returning the matching keys. The walk stops once the keys found
+    /// carry `limit` distinct ids or after `MAX_PATTERN_STEPS` visited nodes.
+    pub(crate) fn find_wildcard(&self, pattern: &str, limit: usize) -> Vec<String> {
+        let pattern: Vec<char> = pattern.chars().collect();
+        let mut keys = Vec::new();
+        let mut found_ids = HashSet::new();
+        let mut visited: HashSet<(*const TrieNode, usize)> = HashSet::new();
+        let mut stack: Vec<(&TrieNode, usize, String)> = vec![(&self.root, 0, String::new())];
+        let mut steps = 0;
+        while let Some((node, pi, key)) = stack.pop() {
+            if found_ids.len() >= limit || steps >= MAX_PATTERN_STEPS {
+                break;
+            }
+            // several `*` can lead to the same node at the same pattern position
+            if !visited.insert((node as *const TrieNode, pi)) {
+                continue;
+            }
+            steps += 1;
+            if pi == pattern.len() {
+                if node.is_word_end {
+                    found_ids.extend(node.ids.iter().flatten().cloned());
+                    keys.push(key);
+                }
+                continue;
+            }
+            match pattern[pi] {
+                '*' => {
+                    for (c, child) in &node.children {
+                        stack.push((child, pi, format!("{}{}", key, c)));
+                    }
+                    stack.push((node, pi + 1, key));
+                }
+                '?' => {
+                    for (c, child) in &node.children {
+                        stack.push((child, pi + 1, format!("{}{}", key, c)));
+                    }
+                }
+                c => {
+                    if let Some(child) = node.children.get(&c) {
+                        stack.push((child, pi + 1, format!("{}{}", key, c)));
+                    }
+                }
+            }
+        }
+        keys
+    }
+
     fn get_all_ids_from(&self, node: &TrieNode, limit: usize) -> Vec<String> {
         let mut found_ids = HashSet::new();
         let mut ids = Vec::new();
@@ -140,6 +197,35 @@ mod tests {
         assert!(vec.contains(&String::from("3")));
     }
 
+    #[test]
+    fn test_trie_exact() {
+        let mut trie = Trie::new();
+
+        trie.insert(String::from("lab"), String::from("1"));
+        trie.insert(String::from("labrador"), String::from("2"));
+
+        assert_eq!(trie.find_exact("lab"), vec![String::from("1")]);
+        assert!(trie.find_exact("labr").is_empty());
+    }
+
+    #[test]
+    fn test_trie_wildcard() {
+        let mut trie = Trie::new();
+
+        trie.insert(String::from("hitkatev"), String::from("1"));
+        trie.insert(String::from("hitlabesh"), String::from("2"));
+        trie.insert(String::from("katav"), String::from("3"));
+
+        let mut vec = trie.find_wildcard("hit*e?", 15);
+        vec.sort();
+        assert_eq!(vec, vec![String::from("hitkatev")]);
+        assert_eq!(trie.find_wildcard("hit*", 15).len(), 2);
+        assert_eq!(trie.find_wildcard("*a*", 15).len(), 3);
+        assert_eq!(trie.find_wildcard("k?tav", 15), vec![String::from("katav")]);
+        assert!(trie.find_wildcard("k?ta", 15).is_empty());
+        assert_eq!(trie.find_wildcard("*", 2).len(), 2);
+    }
+
     #[test]
     fn test_table() {
         let test_cases = vec![
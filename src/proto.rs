@@ -1,12 +1,15 @@
 use std::collections::HashMap;
 
 use crate::proto::worddata::{
-    Binyan, Gender, Number, Person, Tense, WordData as WordDataPB, WordDataList,
+    Argument, Binyan, Gender, Government as GovernmentPB, Number, PartOfSpeech, Person,
+    Possessor as PossessorPB, State, Tense, WordData as WordDataPB, WordDataList,
     WordForm as WordFormPB,
 };
 use lazy_static::lazy_static;
 
-use crate::word_dto::{WordData, WordForm};
+use crate::gizra::classify_root;
+
+use crate::word_dto::{Government, Possessor, WordData, WordForm};
 pub(crate) const WORDS_PB: &[u8] = include_bytes!("../words/words.pb");
 mod build;
 pub(crate) mod worddata;
@@ -37,6 +40,10 @@ pub(crate) fn convert_pb_to_dto(src: Vec<WordDataPB>) -> Vec<WordData> {
 }
 
 fn convert_word_data_pb_to_dto(word_data_pb: WordDataPB) -> WordData {
+    let part_of_speech = convert_part_of_speech_pb_to_dto(word_data_pb.part_of_speech);
+    if part_of_speech != "verb" {
+        return convert_non_verb_pb_to_dto(word_data_pb, part_of_speech);
+    }
     let passives = if word_data_pb.passive.is_empty() {
         None
     } else {
@@ -50,6 +57,7 @@ fn convert_word_data_pb_to_dto(word_data_pb: WordDataPB) -> WordData {
     };
 
     let passive_binyan = word_data_pb.passive_binyan.map(convert_binyan_pb_to_dto);
+    let gizrot = classify_root(&word_data_pb.root);
     WordData {
         url_id: word_data_pb.url_id,
         word: word_data_pb.word,
@@ -65,6 +73,55 @@ fn convert_word_data_pb_to_dto(word_data_pb: WordDataPB) -> WordData {
         binyan: convert_binyan_pb_to_dto(word_data_pb.binyan),
         passive: passives,
         passive_binyan,
+        gizrot,
+        synthetic: false,
+        irregular_cells: vec![],
+        infinitives: vec![],
+        part_of_speech,
+        mishkal: word_data_pb.mishkal,
+        gender: word_data_pb.gender.map(convert_gender_pb_to_dto),
+        government: convert_government_pb_to_dto(word_data_pb.government),
+    }
+}
+
+/// Words other than verbs have no binyan, and their forms no tense nor, except for the
+/// pronominal suffixes of prepositions, person: the zero values prost fills in for those are
+/// dropped.
+fn convert_non_verb_pb_to_dto(word_data_pb: WordDataPB, part_of_speech: String) -> WordData {
+    let gizrot = classify_root(&word_data_pb.root);
+    let has_person = part_of_speech == "preposition";
+    WordData {
+        url_id: word_data_pb.url_id,
+        word: word_data_pb.word,
+        word_en: word_data_pb.word_en,
+        word_normalized: word_data_pb.word_normalized,
+        transcription: word_data_pb.transcription,
+        root: word_data_pb.root,
+        forms: word_data_pb
+            .forms
+            .into_iter()
+            .map(convert_word_form_pb_to_dto)
+            .map(|form| WordForm {
+                tense: String::new(),
+                person: if has_person {
+                    form.person.clone()
+                } else {
+                    String::new()
+                },
+                ..form
+            })
+            .collect(),
+        binyan: String::new(),
+        passive: None,
+        passive_binyan: None,
+        gizrot,
+        synthetic: false,
+        irregular_cells: vec![],
+        infinitives: vec![],
+        part_of_speech,
+        mishkal: word_data_pb.mishkal,
+        gender: word_data_pb.gender.map(convert_gender_pb_to_dto),
+        government: convert_government_pb_to_dto(word_data_pb.government),
     }
 }
 
@@ -79,14 +136,70 @@ fn convert_word_form_pb_to_dto(word_form_pb: WordFormPB) -> WordForm {
         transcription: word_form_pb.transcription,
         meaning: word_form_pb.meaning,
         form_vowelled: word_form_pb.form_vowelled,
+        irregular: false,
+        state: word_form_pb.state.map(convert_state_pb_to_dto),
+        possessor: word_form_pb.possessor.map(convert_possessor_pb_to_dto),
+    }
+}
+
+fn convert_government_pb_to_dto(government_pb: Vec<GovernmentPB>) -> Vec<Government> {
+    government_pb
+        .into_iter()
+        .map(|government| Government {
+            preposition: government.preposition,
+            argument: convert_argument_pb_to_dto(government.argument),
+            examples: government.examples,
+        })
+        .collect()
+}
+
+fn convert_argument_pb_to_dto(argument_pb: i32) -> String {
+    match argument_pb {
+        0 => Argument::Person,
+        1 => Argument::Thing,
+        2 => Argument::Clause,
+        _ => panic!("Invalid argument value: {}", argument_pb),
+    }
+    .as_str_name()
+    .to_lowercase()
+}
+
+fn convert_possessor_pb_to_dto(possessor_pb: PossessorPB) -> Possessor {
+    Possessor {
+        person: convert_person_pb_to_dto(possessor_pb.person),
+        number: convert_number_pb_to_dto(possessor_pb.number),
+        gender: convert_gender_pb_to_dto(possessor_pb.gender),
     }
 }
 
+fn convert_part_of_speech_pb_to_dto(part_of_speech_pb: i32) -> String {
+    match part_of_speech_pb {
+        0 => PartOfSpeech::Verb,
+        1 => PartOfSpeech::Noun,
+        2 => PartOfSpeech::Adjective,
+        3 => PartOfSpeech::Preposition,
+        _ => panic!("Invalid part of speech value: {}", part_of_speech_pb),
+    }
+    .as_str_name()
+    .to_lowercase()
+}
+
+fn convert_state_pb_to_dto(state_pb: i32) -> String {
+    match state_pb {
+        0 => State::Absolute,
+        1 => State::Construct,
+        _ => panic!("Invalid state value: {}", state_pb),
+    }
+    .as_str_name()
+    .to_lowercase()
+}
+
 fn convert_tense_pb_to_dto(tense_pb: i32) -> String {
     match tense_pb {
         0 => Tense::Past,
         1 => Tense::Present,
         2 => Tense::Future,
+        3 => Tense::Imperative,
         _ => panic!("Invalid tense value: {}", tense_pb),
     }
     .as_str_name()
@@ -141,7 +254,8 @@ fn convert_person_pb_to_dto(person_pb: i32) -> String {
     }
     .as_str_name()
     .to_lowercase();
-    unify_all(val)
+    // "P_1ST" is "1st" like in the rest of the crate
+    unify_all(val.trim_start_matches("p_").to_string())
 }
 
 fn unify_all(val: String) -> String {
@@ -155,7 +269,10 @@ fn unify_all(val: String) -> String {
 mod tests {
     use prost::Message;
 
-    use crate::proto::worddata::WordDataList;
+    use crate::proto::worddata::{
+        Argument, Gender, Government, Number, PartOfSpeech, Person, Possessor, State, Tense,
+        WordData as WordDataPB, WordDataList, WordForm as WordFormPB,
+    };
     use crate::proto::{convert_pb_to_dto, WORDS_PB};
     use crate::word_dto::WordData;
 
@@ -165,4 +282,109 @@ mod tests {
         let word_datas: Vec<WordData> = convert_pb_to_dto(word_list.words);
         println!("word_list: {:?}", word_datas.len());
     }
+
+    #[test]
+    fn convert_imperative() {
+        let form = WordFormPB {
+            tense: Tense::Imperative as i32,
+            person: Person::P2nd as i32,
+            form: "כתוב".to_string(),
+            ..Default::default()
+        };
+        let bytes = WordDataList {
+            words: vec![WordDataPB {
+                forms: vec![form],
+                ..Default::default()
+            }],
+        }
+        .encode_to_vec();
+        let word_list: WordDataList = Message::decode(bytes.as_slice()).unwrap();
+        let word = convert_pb_to_dto(word_list.words).remove(0);
+        assert_eq!(word.forms[0].tense, "imperative");
+        assert_eq!(word.forms[0].person, "2nd");
+    }
+
+    #[test]
+    fn convert_persons() {
+        let form = |person: Person| WordFormPB {
+            tense: Tense::Past as i32,
+            person: person as i32,
+            number: Number::Singular as i32,
+            gender: Gender::M as i32,
+            ..Default::default()
+        };
+        let word = WordDataPB {
+            forms: vec![
+                form(Person::P1st),
+                form(Person::P2nd),
+                form(Person::P3rd),
+                form(Person::AllP),
+            ],
+            ..Default::default()
+        };
+        let word = convert_pb_to_dto(vec![word]).remove(0);
+        let persons: Vec<&str> = word.forms.iter().map(|f| f.person.as_str()).collect();
+        assert_eq!(persons, vec!["1st", "2nd", "3rd", "all"]);
+    }
+
+    #[test]
+    fn convert_government() {
+        let verb = WordDataPB {
+            url_id: "1-lechakot".to_string(),
+            government: vec![Government {
+                preposition: "ל".to_string(),
+                argument: Argument::Person as i32,
+                examples: vec!["לחכות לחבר".to_string()],
+            }],
+            ..Default::default()
+        };
+        let word = convert_pb_to_dto(vec![verb]).remove(0);
+        assert_eq!(word.government[0].preposition, "ל");
+        assert_eq!(word.government[0].argument, "person");
+        assert_eq!(word.government[0].examples, vec!["לחכות לחבר"]);
+    }
+
+    #[test]
+    fn convert_noun() {
+        let form = |form: &str, number: Number| WordFormPB {
+            number: number as i32,
+            form: form.to_string(),
+            form_normalized: form.to_string(),
+            ..Default::default()
+        };
+        let noun = WordDataPB {
+            url_id: "6046-sefer".to_string(),
+            word: "ספר".to_string(),
+            word_normalized: "ספר".to_string(),
+            part_of_speech: PartOfSpeech::Noun as i32,
+            mishkal: Some("CeCeC".to_string()),
+            gender: Some(Gender::M as i32),
+            forms: vec![
+                WordFormPB {
+                    state: Some(State::Construct as i32),
+                    ..form("ספרי", Number::Plural)
+                },
+                WordFormPB {
+                    possessor: Some(Possessor {
+                        person: Person::P3rd as i32,
+                        number: Number::Singular as i32,
+                        gender: Gender::M as i32,
+                    }),
+                    ..form("ספריו", Number::Plural)
+                },
+            ],
+            ..Default::default()
+        };
+        let word = convert_pb_to_dto(vec![noun]).remove(0);
+        assert_eq!(word.part_of_speech, "noun");
+        assert_eq!(word.binyan, "");
+        assert_eq!(word.gender.as_deref(), Some("m"));
+        assert_eq!(word.forms[0].tense, "");
+        assert_eq!(word.forms[0].state.as_deref(), Some("construct"));
+        let possessor = word.forms[1].possessor.as_ref().unwrap();
+        assert_eq!(
+            (possessor.person.as_str(), possessor.number.as_str()),
+            ("3rd", "singular")
+        );
+    }
 }
@@ -21,6 +21,32 @@ pub struct WordForm {
     pub meaning: ::prost::alloc::string::String,
     #[prost(string, optional, tag = "9")]
     pub form_vowelled: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "State", optional, tag = "10")]
+    pub state: ::core::option::Option<i32>,
+    #[prost(message, optional, tag = "11")]
+    pub possessor: ::core::option::Option<Possessor>,
+}
+/// Owner expressed by a possessive suffix, e.g. 3rd singular masculine in "ספריו"
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Possessor {
+    #[prost(enumeration = "Person", tag = "1")]
+    pub person: i32,
+    #[prost(enumeration = "Number", tag = "2")]
+    pub number: i32,
+    #[prost(enumeration = "Gender", tag = "3")]
+    pub gender: i32,
+}
+/// Preposition a verb governs, e.g. "ל" + person for "לחכות", with example phrases
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Government {
+    #[prost(string, tag = "1")]
+    pub preposition: ::prost::alloc::string::String,
+    #[prost(enumeration = "Argument", tag = "2")]
+    pub argument: i32,
+    #[prost(string, repeated, tag = "3")]
+    pub examples: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -49,6 +75,14 @@ pub struct WordData {
     pub passive: ::prost::alloc::vec::Vec<WordForm>,
     #[prost(enumeration = "Binyan", optional, tag = "12")]
     pub passive_binyan: ::core::option::Option<i32>,
+    #[prost(enumeration = "PartOfSpeech", tag = "13")]
+    pub part_of_speech: i32,
+    #[prost(string, optional, tag = "14")]
+    pub mishkal: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "Gender", optional, tag = "15")]
+    pub gender: ::core::option::Option<i32>,
+    #[prost(message, repeated, tag = "16")]
+    pub government: ::prost::alloc::vec::Vec<Government>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -217,3 +251,92 @@ impl Binyan {
         }
     }
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PartOfSpeech {
+    Verb = 0,
+    Noun = 1,
+    Adjective = 2,
+    Preposition = 3,
+}
+impl PartOfSpeech {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PartOfSpeech::Verb => "VERB",
+            PartOfSpeech::Noun => "NOUN",
+            PartOfSpeech::Adjective => "ADJECTIVE",
+            PartOfSpeech::Preposition => "PREPOSITION",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "VERB" => Some(Self::Verb),
+            "NOUN" => Some(Self::Noun),
+            "ADJECTIVE" => Some(Self::Adjective),
+            "PREPOSITION" => Some(Self::Preposition),
+            _ => None,
+        }
+    }
+}
+/// State of a noun or adjective form, forms with a possessive suffix have none
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum State {
+    Absolute = 0,
+    Construct = 1,
+}
+impl State {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            State::Absolute => "ABSOLUTE",
+            State::Construct => "CONSTRUCT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ABSOLUTE" => Some(Self::Absolute),
+            "CONSTRUCT" => Some(Self::Construct),
+            _ => None,
+        }
+    }
+}
+/// What a verb takes as the object of a preposition
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Argument {
+    Person = 0,
+    Thing = 1,
+    Clause = 2,
+}
+impl Argument {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Argument::Person => "PERSON",
+            Argument::Thing => "THING",
+            Argument::Clause => "CLAUSE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PERSON" => Some(Self::Person),
+            "THING" => Some(Self::Thing),
+            "CLAUSE" => Some(Self::Clause),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,675 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "wasm-support")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::gizra::canonical_gizra;
+use crate::util::{is_hebrew_letter, normalize, normalize_root};
+use crate::word_dto::{MatchedForm, WordData, WordForm};
+
+/// Structured search over the index: an optional text match combined with grammatical filters.
+/// Every filter that is set must hold for a form to be returned, unset filters match anything.
+/// Forms whose person or gender is "all" satisfy any person or gender filter.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+pub struct Query {
+    pub(crate) text: Option<String>,
+    pub(crate) binyan: Option<String>,
+    pub(crate) passive_binyan: Option<String>,
+    pub(crate) root: Option<String>,
+    pub(crate) gizra: Option<String>,
+    pub(crate) tense: Option<String>,
+    pub(crate) person: Option<String>,
+    pub(crate) number: Option<String>,
+    pub(crate) gender: Option<String>,
+    pub(crate) irregular: Option<bool>,
+    pub(crate) part_of_speech: Option<String>,
+    pub(crate) state: Option<String>,
+    pub(crate) governs: Option<String>,
+}
+
+#[cfg_attr(feature = "wasm-support", wasm_bindgen)]
+impl Query {
+    #[cfg_attr(feature = "wasm-support", wasm_bindgen(constructor))]
+    pub fn new() -> Query {
+        Query::default()
+    }
+
+    /// Hebrew form (vowelled or not) or English translation the forms must match.
+    pub fn text(mut self, text: &str) -> Query {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// Binyan of the active forms, e.g. "HITPA'EL" or "hitpael".
+    pub fn binyan(mut self, binyan: &str) -> Query {
+        self.binyan = Some(canonical_binyan(binyan).unwrap_or_else(|| binyan.to_string()));
+        self
+    }
+
+    /// Binyan of the passive forms, e.g. "PU'AL" or "hufal".
+    pub fn passive_binyan(mut self, binyan: &str) -> Query {
+        self.passive_binyan = Some(canonical_binyan(binyan).unwrap_or_else(|| binyan.to_string()));
+        self
+    }
+
+    /// Root letters, separators and final letters are ignored: "ש-מ-ר" and "שמר" are the same.
+    pub fn root(mut self, root: &str) -> Query {
+        self.root = Some(normalize_root(root));
+        self
+    }
+
+    /// Weak root class, e.g. "lamed_he", "ל״ה" or "pe-nun".
+    pub fn gizra(mut self, gizra: &str) -> Query {
+        self.gizra = Some(canonical_gizra(gizra).unwrap_or_else(|| gizra.to_string()));
+        self
+    }
+
+    pub fn tense(mut self, tense: &str) -> Query {
+        self.tense = Some(canonical_tense(tense).unwrap_or_else(|| tense.to_string()));
+        self
+    }
+
+    pub fn person(mut self, person: &str) -> Query {
+        self.person = Some(canonical_person(person).unwrap_or_else(|| person.to_string()));
+        self
+    }
+
+    pub fn number(mut self, number: &str) -> Query {
+        self.number = Some(canonical_number(number).unwrap_or_else(|| number.to_string()));
+        self
+    }
+
+    pub fn gender(mut self, gender: &str) -> Query {
+        self.gender = Some(canonical_gender(gender).unwrap_or_else(|| gender.to_string()));
+        self
+    }
+
+    /// Keeps only forms that deviate from (`true`) or follow (`false`) the regular paradigm.
+    /// Only verbs with a regular root are compared, the forms of weak roots never deviate.
+    pub fn irregular(mut self, irregular: bool) -> Query {
+        self.irregular = Some(irregular);
+        self
+    }
+
+    pub fn part_of_speech(mut self, part_of_speech: &str) -> Query {
+        self.part_of_speech = Some(
+            canonical_part_of_speech(part_of_speech).unwrap_or_else(|| part_of_speech.to_string()),
+        );
+        self
+    }
+
+    /// State of noun forms, "absolute" or "construct".
+    pub fn state(mut self, state: &str) -> Query {
+        self.state = Some(canonical_state(state).unwrap_or_else(|| state.to_string()));
+        self
+    }
+
+    /// Keeps only verbs governing the preposition, e.g. "ב" or "את".
+    pub fn governs(mut self, preposition: &str) -> Query {
+        self.governs =
+            Some(canonical_preposition(preposition).unwrap_or_else(|| preposition.to_string()));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Query::default()
+    }
+}
+
+pub(crate) fn canonical_binyan(val: &str) -> Option<String> {
+    let key: String = val
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .collect::<String>()
+        .to_uppercase();
+    let binyan = match key.as_str() {
+        "PAAL" | "KAL" | "QAL" => "PA'AL",
+        "NIFAL" => "NIF'AL",
+        "PIEL" => "PI'EL",
+        "PUAL" => "PU'AL",
+        "HIFIL" => "HIF'IL",
+        "HUFAL" => "HUF'AL",
+        "HITPAEL" => "HITPA'EL",
+        _ => return None,
+    };
+    Some(binyan.to_string())
+}
+
+pub(crate) fn canonical_flag(val: &str) -> Option<bool> {
+    match val.trim().to_lowercase().as_str() {
+        "yes" | "true" | "1" => Some(true),
+        "no" | "false" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+pub(crate) fn canonical_part_of_speech(val: &str) -> Option<String> {
+    let part_of_speech = match val.trim().to_lowercase().as_str() {
+        "v" | "verb" => "verb",
+        "n" | "noun" => "noun",
+        "adj" | "adjective" => "adjective",
+        "prep" | "preposition" => "preposition",
+        _ => return None,
+    };
+    Some(part_of_speech.to_string())
+}
+
+pub(crate) fn canonical_state(val: &str) -> Option<String> {
+    let state = match val.trim().to_lowercase().as_str() {
+        "abs" | "absolute" => "absolute",
+        "cons" | "construct" | "smichut" => "construct",
+        _ => return None,
+    };
+    Some(state.to_string())
+}
+
+/// The letters of a preposition, without niqqud or a trailing maqaf ("לְ־" is "ל").
+pub(crate) fn canonical_preposition(val: &str) -> Option<String> {
+    let preposition: String = normalize(val)
+        .chars()
+        .filter(|c| is_hebrew_letter(*c))
+        .collect();
+    if preposition.is_empty() {
+        return None;
+    }
+    Some(preposition)
+}
+
+pub(crate) fn canonical_tense(val: &str) -> Option<String> {
+    let tense = match val.trim().to_lowercase().as_str() {
+        "past" => "past",
+        "present" => "present",
+        "future" => "future",
+        "imperative" | "imp" => "imperative",
+        "infinitive" | "inf" => "infinitive",
+        _ => return None,
+    };
+    Some(tense.to_string())
+}
+
+pub(crate) fn canonical_person(val: &str) -> Option<String> {
+    let person = match val.trim().to_lowercase().as_str() {
+        "1" | "1st" | "first" => "1st",
+        "2" | "2nd" | "second" => "2nd",
+        "3" | "3rd" | "third" => "3rd",
+        "all" => "all",
+        _ => return None,
+    };
+    Some(person.to_string())
+}
+
+pub(crate) fn canonical_number(val: &str) -> Option<String> {
+    let number = match val.trim().to_lowercase().as_str() {
+        "sg" | "sing" | "singular" => "singular",
+        "pl" | "plur" | "plural" => "plural",
+        _ => return None,
+    };
+    Some(number.to_string())
+}
+
+pub(crate) fn canonical_gender(val: &str) -> Option<String> {
+    let gender = match val.trim().to_lowercase().as_str() {
+        "m" | "masc" | "masculine" => "m",
+        "f" | "fem" | "feminine" => "f",
+        "all" => "all",
+        _ => return None,
+    };
+    Some(gender.to_string())
+}
+
+/// A single form of a word: `kind` and `index` have the same meaning as in `MatchedForm`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) struct FormRef {
+    pub(crate) url_id: String,
+    pub(crate) kind: usize,
+    pub(crate) index: usize,
+}
+
+impl FormRef {
+    pub(crate) fn new(url_id: &str, kind: usize, index: usize) -> FormRef {
+        FormRef {
+            url_id: url_id.to_string(),
+            kind,
+            index,
+        }
+    }
+
+    pub(crate) fn matched_form(&self) -> MatchedForm {
+        MatchedForm {
+            index: self.index,
+            kind: self.kind,
+        }
+    }
+}
+
+/// Every form of a word, the infinitive of a verb included.
+pub(crate) fn all_form_refs(word: &WordData) -> Vec<FormRef> {
+    let infinitives = if word.is_verb() {
+        word.infinitives.len().max(1)
+    } else {
+        0
+    };
+    let mut refs: Vec<FormRef> = (0..infinitives)
+        .map(|i| FormRef::new(&word.url_id, 0, i))
+        .collect();
+    refs.extend((0..word.forms.len()).map(|i| FormRef::new(&word.url_id, 1, i)));
+    if let Some(passive) = &word.passive {
+        refs.extend((0..passive.len()).map(|i| FormRef::new(&word.url_id, 2, i)));
+    }
+    refs
+}
+
+fn posting_key(field: &str, value: &str) -> String {
+    format!("{}:{}", field, value)
+}
+
+/// Posting lists from "field:value" to the forms carrying that value.
+#[derive(Default)]
+pub(crate) struct Postings {
+    lists: HashMap<String, HashSet<FormRef>>,
+}
+
+impl Postings {
+    pub(crate) fn build(words: &[WordData]) -> Postings {
+        let mut postings = Postings::default();
+        for word in words {
+            let root = normalize_root(&word.root);
+            for form_ref in all_form_refs(word) {
+                for gizra in &word.gizrot {
+                    postings.add("gizra", gizra, form_ref.clone());
+                }
+                postings.add("part_of_speech", &word.part_of_speech, form_ref.clone());
+                for government in &word.government {
+                    if let Some(preposition) = canonical_preposition(&government.preposition) {
+                        postings.add("governs", &preposition, form_ref.clone());
+                    }
+                }
+            }
+            let infinitive_irregular = word.irregular_cells.iter().any(|c| c == "infinitive");
+            for form_ref in all_form_refs(word).into_iter().filter(|r| r.kind == 0) {
+                postings.add(
+                    "irregular",
+                    &infinitive_irregular.to_string(),
+                    form_ref.clone(),
+                );
+                postings.add("tense", "infinitive", form_ref.clone());
+                postings.add("root", &root, form_ref.clone());
+                postings.add("binyan", &word.binyan, form_ref);
+            }
+            for (i, form) in word.forms.iter().enumerate() {
+                let form_ref = FormRef::new(&word.url_id, 1, i);
+                postings.add("root", &root, form_ref.clone());
+                postings.add("binyan", &word.binyan, form_ref.clone());
+                if let Some(state) = &form.state {
+                    postings.add("state", state, form_ref.clone());
+                }
+                postings.add_form(form, form_ref);
+            }
+            if let Some(passive) = &word.passive {
+                let passive_binyan = word.passive_binyan.clone().unwrap_or_default();
+                for (i, form) in passive.iter().enumerate() {
+                    let form_ref = FormRef::new(&word.url_id, 2, i);
+                    postings.add("root", &root, form_ref.clone());
+                    postings.add("passive_binyan", &passive_binyan, form_ref.clone());
+                    postings.add_form(form, form_ref);
+                }
+            }
+        }
+        postings
+    }
+
+    fn add_form(&mut self, form: &WordForm, form_ref: FormRef) {
+        self.add("tense", &form.tense, form_ref.clone());
+        self.add("person", &form.person, form_ref.clone());
+        self.add("number", &form.number, form_ref.clone());
+        self.add("gender", &form.gender, form_ref.clone());
+        self.add("irregular", &form.irregular.to_string(), form_ref);
+    }
+
+    pub(crate) fn add(&mut self, field: &str, value: &str, form_ref: FormRef) {
+        self.lists
+            .entry(posting_key(field, value))
+            .or_default()
+            .insert(form_ref);
+    }
+
+    pub(crate) fn get(&self, field: &str, value: &str) -> Option<&HashSet<FormRef>> {
+        self.lists.get(&posting_key(field, value))
+    }
+
+    /// Forms carrying `value`, plus the forms marked "all" for fields where that applies.
+    fn matching(&self, field: &str, value: &str) -> HashSet<FormRef> {
+        let mut result = self.get(field, value).cloned().unwrap_or_default();
+        if (field == "person" || field == "gender") && value != "all" {
+            if let Some(all) = self.get(field, "all") {
+                result.extend(all.iter().cloned());
+            }
+        }
+        result
+    }
+
+    /// Intersects the posting lists of every filter set on the query.
+    /// Returns `None` when the query has no filters at all.
+    pub(crate) fn evaluate(&self, query: &Query) -> Option<HashSet<FormRef>> {
+        let irregular = query.irregular.map(|v| v.to_string());
+        let filters = [
+            ("binyan", &query.binyan),
+            ("passive_binyan", &query.passive_binyan),
+            ("root", &query.root),
+            ("gizra", &query.gizra),
+            ("tense", &query.tense),
+            ("person", &query.person),
+            ("number", &query.number),
+            ("gender", &query.gender),
+            ("irregular", &irregular),
+            ("part_of_speech", &query.part_of_speech),
+            ("state", &query.state),
+            ("governs", &query.governs),
+        ];
+        let mut sets: Vec<HashSet<FormRef>> = filters
+            .iter()
+            .filter_map(|(field, value)| value.as_ref().map(|v| self.matching(field, v)))
+            .collect();
+        // intersect starting from the smallest list
+        sets.sort_by_key(|set| set.len());
+        let mut iter = sets.into_iter();
+        let first = iter.next()?;
+        Some(iter.fold(first, |acc, set| {
+            acc.into_iter().filter(|r| set.contains(r)).collect()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::proto::convert_pb_to_dto;
+    use crate::proto::worddata::{
+        Argument, Binyan, Gender, Government, Number, PartOfSpeech, Person, State, Tense,
+        WordData as WordDataPB, WordForm as WordFormPB,
+    };
+    use crate::query::Query;
+    use crate::test_data::test_index;
+    use crate::word_index::WordIndex;
+
+    fn form_pb(
+        tense: Tense,
+        person: Person,
+        number: Number,
+        gender: Gender,
+        form: &str,
+    ) -> WordFormPB {
+        WordFormPB {
+            tense: tense as i32,
+            person: person as i32,
+            number: number as i32,
+            gender: gender as i32,
+            form: form.to_string(),
+            form_normalized: form.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// An index built the way `init_local` builds it, from protobuf entries.
+    fn pb_index() -> WordIndex {
+        let verb = WordDataPB {
+            url_id: "927-lichtov".to_string(),
+            word: "לכתוב".to_string(),
+            word_normalized: "לכתוב".to_string(),
+            root: "כ - ת - ב".to_string(),
+            binyan: Binyan::Paal as i32,
+            forms: vec![
+                form_pb(
+                    Tense::Present,
+                    Person::AllP,
+                    Number::Singular,
+                    Gender::F,
+                    "כותבת",
+                ),
+                form_pb(
+                    Tense::Past,
+                    Person::P1st,
+                    Number::Singular,
+                    Gender::AllG,
+                    "כתבתי",
+                ),
+                form_pb(
+                    Tense::Past,
+                    Person::P3rd,
+                    Number::Singular,
+                    Gender::M,
+                    "כתיב",
+                ),
+                form_pb(
+                    Tense::Past,
+                    Person::P3rd,
+                    Number::Plural,
+                    Gender::AllG,
+                    "כתבו",
+                ),
+                form_pb(
+                    Tense::Future,
+                    Person::P2nd,
+                    Number::Singular,
+                    Gender::F,
+                    "תכתבי",
+                ),
+                form_pb(
+                    Tense::Imperative,
+                    Person::P2nd,
+                    Number::Plural,
+                    Gender::M,
+                    "כתבו",
+                ),
+            ],
+            passive_binyan: Some(Binyan::Nifal as i32),
+            passive: vec![form_pb(
+                Tense::Past,
+                Person::P3rd,
+                Number::Singular,
+                Gender::M,
+                "נכתב",
+            )],
+            government: vec![Government {
+                preposition: "ל".to_string(),
+                argument: Argument::Person as i32,
+                examples: vec![],
+            }],
+            ..Default::default()
+        };
+        let noun = WordDataPB {
+            url_id: "6046-sefer".to_string(),
+            word: "ספר".to_string(),
+            word_normalized: "ספר".to_string(),
+            root: "ס - פ - ר".to_string(),
+            part_of_speech: PartOfSpeech::Noun as i32,
+            gender: Some(Gender::M as i32),
+            forms: vec![WordFormPB {
+                state: Some(State::Construct as i32),
+                ..form_pb(Tense::Past, Person::P1st, Number::Plural, Gender::M, "ספרי")
+            }],
+            ..Default::default()
+        };
+        WordIndex::build(convert_pb_to_dto(vec![verb, noun]))
+    }
+
+    fn pb_forms(index: &WordIndex, query: &Query) -> Vec<String> {
+        index
+            .query(query)
+            .iter()
+            .flat_map(|r| {
+                r.matching_forms.iter().map(|m| match m.kind {
+                    0 => r.word.infinitives[m.index].form.clone(),
+                    1 => r.word.forms[m.index].form.clone(),
+                    _ => r.word.passive.as_ref().unwrap()[m.index].form.clone(),
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pb_query_binyan() {
+        let index = pb_index();
+        assert_eq!(
+            pb_forms(&index, &Query::new().binyan("paal").tense("past")).len(),
+            3
+        );
+        assert!(pb_forms(&index, &Query::new().binyan("PI'EL")).is_empty());
+        assert_eq!(
+            pb_forms(&index, &Query::new().passive_binyan("nifal")),
+            vec!["נכתב"]
+        );
+    }
+
+    #[test]
+    fn pb_query_root_and_gizra() {
+        let index = pb_index();
+        assert_eq!(pb_forms(&index, &Query::new().root("כתב")).len(), 8);
+        assert_eq!(
+            pb_forms(&index, &Query::new().gizra("regular").tense("future")),
+            vec!["תכתבי"]
+        );
+    }
+
+    #[test]
+    fn pb_query_tense() {
+        let index = pb_index();
+        assert_eq!(
+            pb_forms(&index, &Query::new().tense("imperative")),
+            vec!["כתבו"]
+        );
+        assert_eq!(
+            pb_forms(&index, &Query::new().tense("present")),
+            vec!["כותבת"]
+        );
+        assert_eq!(
+            pb_forms(&index, &Query::new().tense("infinitive")),
+            vec!["לכתוב"]
+        );
+    }
+
+    #[test]
+    fn pb_query_person() {
+        let index = pb_index();
+        assert_eq!(
+            pb_forms(&index, &Query::new().person("1").tense("past")),
+            vec!["כתבתי"]
+        );
+        let mut forms = pb_forms(&index, &Query::new().person("3rd").binyan("paal"));
+        forms.sort();
+        // the present is for every person
+        assert_eq!(forms, vec!["כותבת", "כתבו", "כתיב"]);
+    }
+
+    #[test]
+    fn pb_query_number_and_gender() {
+        let index = pb_index();
+        let mut forms = pb_forms(&index, &Query::new().number("plural").binyan("paal"));
+        forms.sort();
+        assert_eq!(forms, vec!["כתבו", "כתבו"]);
+        let mut forms = pb_forms(&index, &Query::new().gender("f").tense("past"));
+        forms.sort();
+        assert_eq!(forms, vec!["כתבו", "כתבתי"]);
+    }
+
+    #[test]
+    fn pb_query_irregular() {
+        let index = pb_index();
+        assert_eq!(
+            pb_forms(&index, &Query::new().irregular(true)),
+            vec!["כתיב"]
+        );
+    }
+
+    #[test]
+    fn pb_query_part_of_speech_state_and_government() {
+        let index = pb_index();
+        assert_eq!(
+            pb_forms(&index, &Query::new().part_of_speech("noun")),
+            vec!["ספרי", "ספר"]
+        );
+        assert_eq!(
+            pb_forms(&index, &Query::new().state("construct")),
+            vec!["ספרי"]
+        );
+        assert_eq!(
+            pb_forms(&index, &Query::new().governs("לְ־").tense("future")),
+            vec!["תכתבי"]
+        );
+    }
+
+    #[test]
+    fn query_by_binyan_and_features() {
+        let index = test_index();
+        let query = Query::new()
+            .binyan("paal")
+            .root("כ-ת-ב")
+            .tense("future")
+            .person("2")
+            .gender("f");
+        let results = index.query(&query);
+        assert_eq!(results.len(), 1);
+        let forms: Vec<&str> = results[0]
+            .matching_forms
+            .iter()
+            .map(|m| results[0].word.forms[m.index].form.as_str())
+            .collect();
+        assert_eq!(forms, vec!["תכתבי", "תכתובנה"]);
+    }
+
+    #[test]
+    fn query_passive_binyan() {
+        let index = test_index();
+        let results = index.query(
+            &Query::new()
+                .passive_binyan("pual")
+                .tense("past")
+                .person("3"),
+        );
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].matching_forms.len(), 3);
+        assert!(results[0].matching_forms.iter().all(|m| m.kind == 2));
+        assert!(index.query(&Query::new().binyan("PU'AL")).is_empty());
+    }
+
+    #[test]
+    fn query_text_with_filters() {
+        let index = test_index();
+        let results = index.query(&Query::new().text("כתבת").gender("f"));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].matching_forms.len(), 1);
+        assert_eq!(results[0].matching_forms[0].index, 7);
+
+        let results = index.query(&Query::new().text("to arrange").tense("present"));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].matching_forms.len(), 8);
+        assert!(index.query(&Query::new()).is_empty());
+    }
+
+    #[test]
+    fn query_part_of_speech_and_state() {
+        let index = test_index();
+        let results = index.search("pos:noun state:construct").unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].word.url_id, "6046-sefer");
+        assert_eq!(results[0].matching_forms.len(), 2);
+        assert_eq!(index.query(&Query::new().part_of_speech("v")).len(), 2);
+        assert!(index.search("pos:noun tense:past").unwrap().is_empty());
+        let results = index.search("pos:adj gender:f").unwrap();
+        assert_eq!(results[0].word.url_id, "4893-sadir");
+        assert_eq!(results[0].matching_forms.len(), 4);
+        assert!(index.search("pos:adverb").is_err());
+    }
+
+    #[test]
+    fn query_government() {
+        let index = test_index();
+        let results = index.search("governs:לְ־").unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].word.url_id, "927-lichtov");
+        assert_eq!(index.query(&Query::new().governs("את")).len(), 2);
+        assert!(index.search("governs:ב").unwrap().is_empty());
+        assert!(index.search("governs:-").is_err());
+    }
+}
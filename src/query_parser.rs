@@ -0,0 +1,425 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "wasm-support")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::gizra::canonical_gizra;
+use crate::query::{
+    canonical_binyan, canonical_flag, canonical_gender, canonical_number, canonical_part_of_speech,
+    canonical_person, canonical_preposition, canonical_state, canonical_tense, Query,
+};
+
+/// Parsed form of a text query such as `binyan:piel root:כתב -tense:past "to write"`.
+// built once per search, the size of `Term` is not worth a box
+#[allow(clippy::large_enum_variant)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum QueryExpr {
+    Term(Query),
+    And(Vec<QueryExpr>),
+    Or(Vec<QueryExpr>),
+    Not(Box<QueryExpr>),
+}
+
+/// Error returned for malformed queries, `position` is the offset in characters of the token
+/// the parser stopped at.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub position: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(position: usize, message: String) -> ParseError {
+        ParseError { position, message }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, PartialEq)]
+enum TokenKind {
+    LParen,
+    RParen,
+    Not,
+    Or,
+    Field(String),
+    Word(String),
+    Phrase(String),
+}
+
+#[derive(Debug)]
+struct Token {
+    kind: TokenKind,
+    position: usize,
+}
+
+fn is_word_char(c: char) -> bool {
+    !c.is_whitespace() && !matches!(c, '(' | ')' | '"' | ':' | '|')
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let position = i;
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        let kind = match c {
+            '(' => {
+                i += 1;
+                TokenKind::LParen
+            }
+            ')' => {
+                i += 1;
+                TokenKind::RParen
+            }
+            '|' => {
+                i += 1;
+                TokenKind::Or
+            }
+            '-' if chars
+                .get(i + 1)
+                .is_some_and(|n| *n == '(' || *n == '"' || is_word_char(*n)) =>
+            {
+                i += 1;
+                TokenKind::Not
+            }
+            '"' => {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|c| *c == '"')
+                    .ok_or_else(|| ParseError::new(position, "unterminated quote".to_string()))?;
+                let phrase: String = chars[i + 1..i + 1 + end].iter().collect();
+                i += end + 2;
+                TokenKind::Phrase(phrase)
+            }
+            ':' => {
+                return Err(ParseError::new(
+                    position,
+                    "missing field name before ':'".to_string(),
+                ))
+            }
+            _ => {
+                let start = i;
+                while i < chars.len() && is_word_char(chars[i]) {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                if chars.get(i) == Some(&':') {
+                    i += 1;
+                    TokenKind::Field(word.to_lowercase())
+                } else if word == "OR" {
+                    TokenKind::Or
+                } else {
+                    TokenKind::Word(word)
+                }
+            }
+        };
+        tokens.push(Token { kind, position });
+    }
+    Ok(tokens)
+}
+
+/// Deepest nesting of groups and negations accepted, deeper queries are rejected instead of
+/// overflowing the stack.
+const MAX_DEPTH: usize = 64;
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    end: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn position(&self) -> usize {
+        self.peek().map(|t| t.position).unwrap_or(self.end)
+    }
+
+    /// Parses the operand of a group or negation one level deeper.
+    fn parse_nested(
+        &mut self,
+        position: usize,
+        parse: fn(&mut Parser) -> Result<QueryExpr, ParseError>,
+    ) -> Result<QueryExpr, ParseError> {
+        if self.depth == MAX_DEPTH {
+            return Err(ParseError::new(
+                position,
+                "query nested too deeply".to_string(),
+            ));
+        }
+        self.depth += 1;
+        let expr = parse(self);
+        self.depth -= 1;
+        expr
+    }
+
+    fn parse_or(&mut self) -> Result<QueryExpr, ParseError> {
+        let mut items = vec![self.parse_and()?];
+        while matches!(
+            self.peek(),
+            Some(Token {
+                kind: TokenKind::Or,
+                ..
+            })
+        ) {
+            self.pos += 1;
+            items.push(self.parse_and()?);
+        }
+        Ok(if items.len() == 1 {
+            items.pop().unwrap()
+        } else {
+            QueryExpr::Or(items)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<QueryExpr, ParseError> {
+        let mut items = Vec::new();
+        // adjacent bare words are read as a single phrase: `to write` == `"to write"`
+        let mut words: Vec<String> = Vec::new();
+        while let Some(token) = self.peek() {
+            match &token.kind {
+                TokenKind::Or | TokenKind::RParen => break,
+                TokenKind::Word(word) => {
+                    words.push(word.clone());
+                    self.pos += 1;
+                }
+                _ => {
+                    if !words.is_empty() {
+                        items.push(QueryExpr::Term(Query::new().text(&words.join(" "))));
+                        words.clear();
+                    }
+                    items.push(self.parse_unary()?);
+                }
+            }
+        }
+        if !words.is_empty() {
+            items.push(QueryExpr::Term(Query::new().text(&words.join(" "))));
+        }
+        match items.len() {
+            0 => Err(ParseError::new(
+                self.position(),
+                "expected a search term".to_string(),
+            )),
+            1 => Ok(items.pop().unwrap()),
+            _ => Ok(QueryExpr::And(items)),
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<QueryExpr, ParseError> {
+        let position = self.position();
+        let token = self
+            .tokens
+            .get(self.pos)
+            .ok_or_else(|| ParseError::new(position, "expected a search term".to_string()))?;
+        match &token.kind {
+            TokenKind::Not => {
+                self.pos += 1;
+                let expr = self.parse_nested(position, Parser::parse_unary)?;
+                Ok(QueryExpr::Not(Box::new(expr)))
+            }
+            TokenKind::LParen => {
+                self.pos += 1;
+                let expr = self.parse_nested(position, Parser::parse_or)?;
+                match self.peek() {
+                    Some(Token {
+                        kind: TokenKind::RParen,
+                        ..
+                    }) => {
+                        self.pos += 1;
+                        Ok(expr)
+                    }
+                    _ => Err(ParseError::new(position, "unclosed '('".to_string())),
+                }
+            }
+            TokenKind::Phrase(phrase) => {
+                let phrase = phrase.clone();
+                self.pos += 1;
+                Ok(QueryExpr::Term(Query::new().text(&phrase)))
+            }
+            TokenKind::Word(word) => {
+                let word = word.clone();
+                self.pos += 1;
+                Ok(QueryExpr::Term(Query::new().text(&word)))
+            }
+            TokenKind::Field(field) => {
+                let field = field.clone();
+                self.pos += 1;
+                self.parse_field(&field, position)
+            }
+            TokenKind::RParen => Err(ParseError::new(position, "unexpected ')'".to_string())),
+            TokenKind::Or => Err(ParseError::new(position, "unexpected OR".to_string())),
+        }
+    }
+
+    fn parse_field(&mut self, field: &str, field_position: usize) -> Result<QueryExpr, ParseError> {
+        let position = self.position();
+        let value = match self.peek().map(|t| &t.kind) {
+            Some(TokenKind::Word(value)) | Some(TokenKind::Phrase(value)) => value.clone(),
+            _ => {
+                return Err(ParseError::new(
+                    position,
+                    format!("missing value for '{}'", field),
+                ))
+            }
+        };
+        self.pos += 1;
+        let invalid = || ParseError::new(position, format!("invalid {} '{}'", field, value));
+        let query = Query::new();
+        let query = match field {
+            "text" => query.text(&value),
+            "binyan" => query.binyan(&canonical_binyan(&value).ok_or_else(invalid)?),
+            "passive" | "passive_binyan" => {
+                query.passive_binyan(&canonical_binyan(&value).ok_or_else(invalid)?)
+            }
+            "root" => query.root(&value),
+            "gizra" => query.gizra(&canonical_gizra(&value).ok_or_else(invalid)?),
+            "tense" => query.tense(&canonical_tense(&value).ok_or_else(invalid)?),
+            "person" => query.person(&canonical_person(&value).ok_or_else(invalid)?),
+            "number" => query.number(&canonical_number(&value).ok_or_else(invalid)?),
+            "gender" => query.gender(&canonical_gender(&value).ok_or_else(invalid)?),
+            "irregular" => query.irregular(canonical_flag(&value).ok_or_else(invalid)?),
+            "pos" | "part_of_speech" => {
+                query.part_of_speech(&canonical_part_of_speech(&value).ok_or_else(invalid)?)
+            }
+            "state" => query.state(&canonical_state(&value).ok_or_else(invalid)?),
+            "governs" => query.governs(&canonical_preposition(&value).ok_or_else(invalid)?),
+            _ => {
+                return Err(ParseError::new(
+                    field_position,
+                    format!("unknown field '{}'", field),
+                ))
+            }
+        };
+        Ok(QueryExpr::Term(query))
+    }
+}
+
+/// Parses the query syntax: `field:value` filters (binyan, passive, root, gizra, tense, person,
+/// number, gender, irregular, pos, state, governs, text), quoted phrases, bare words, `-` for
+/// negation, `OR` (or `|`) between alternatives and parentheses for grouping. Terms next to each
+/// other must all match. Groups and negations nest at most 64 levels deep.
+pub fn parse_query(input: &str) -> Result<QueryExpr, ParseError> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        end: input.chars().count(),
+        depth: 0,
+    };
+    let expr = parser.parse_or()?;
+    if let Some(token) = parser.peek() {
+        return Err(ParseError::new(
+            token.position,
+            "unexpected ')'".to_string(),
+        ));
+    }
+    Ok(expr)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::Query;
+    use crate::query_parser::{parse_query, QueryExpr};
+    use crate::test_data::test_index;
+
+    #[test]
+    fn parse_filters_and_phrase() {
+        let expr = parse_query(r#"binyan:piel root:כתב tense:past person:3 "to write""#).unwrap();
+        assert_eq!(
+            expr,
+            QueryExpr::And(vec![
+                QueryExpr::Term(Query::new().binyan("PI'EL")),
+                QueryExpr::Term(Query::new().root("כתב")),
+                QueryExpr::Term(Query::new().tense("past")),
+                QueryExpr::Term(Query::new().person("3rd")),
+                QueryExpr::Term(Query::new().text("to write")),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_negation_and_or_groups() {
+        let expr = parse_query("-binyan:paal (tense:past OR tense:future)").unwrap();
+        assert_eq!(
+            expr,
+            QueryExpr::And(vec![
+                QueryExpr::Not(Box::new(QueryExpr::Term(Query::new().binyan("paal")))),
+                QueryExpr::Or(vec![
+                    QueryExpr::Term(Query::new().tense("past")),
+                    QueryExpr::Term(Query::new().tense("future")),
+                ]),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_errors_report_position() {
+        let cases = [
+            ("binyan:foo", 7, "invalid binyan 'foo'"),
+            ("mood:past", 0, "unknown field 'mood'"),
+            ("tense:past \"to write", 11, "unterminated quote"),
+            ("(tense:past", 0, "unclosed '('"),
+            ("tense:past)", 10, "unexpected ')'"),
+            ("tense:", 6, "missing value for 'tense'"),
+            ("", 0, "expected a search term"),
+            ("tense:past OR", 13, "expected a search term"),
+        ];
+        for (input, position, message) in cases {
+            let err = parse_query(input).unwrap_err();
+            assert_eq!(err.position, position, "wrong position for '{}'", input);
+            assert_eq!(err.message, message, "wrong message for '{}'", input);
+        }
+    }
+
+    #[test]
+    fn nesting_is_limited() {
+        let nested = |depth: usize| format!("{}tense:past{}", "(".repeat(depth), ")".repeat(depth));
+        assert!(parse_query(&nested(64)).is_ok());
+        let err = parse_query(&nested(65)).unwrap_err();
+        assert_eq!(err.position, 64);
+        assert_eq!(err.message, "query nested too deeply");
+        let err = parse_query(&format!("{}tense:past", "-".repeat(100_000))).unwrap_err();
+        assert_eq!(err.position, 64);
+        assert!(parse_query(&"(".repeat(100_000)).is_err());
+    }
+
+    #[test]
+    fn search_with_query_language() {
+        let index = test_index();
+        let results = index
+            .search("root:כתב tense:past person:3 -gender:f")
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        let forms: Vec<&str> = results[0]
+            .matching_forms
+            .iter()
+            .map(|m| results[0].word.forms[m.index].form.as_str())
+            .collect();
+        // "they wrote" has gender "all" and so counts as feminine too
+        assert_eq!(forms, vec!["כתב"]);
+
+        let results = index
+            .search("(binyan:paal OR passive:pual) tense:imperative")
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].matching_forms.len(), 4);
+        assert!(index.search("binyan:").is_err());
+    }
+}
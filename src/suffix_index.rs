@@ -0,0 +1,111 @@
+use std::cmp::Ordering;
+
+/// Suffix array over the normalized forms, answers substring and suffix queries with the
+/// offset (in characters) of every occurrence.
+pub(crate) struct SuffixIndex {
+    keys: Vec<Vec<char>>,
+    // (key, offset) pairs sorted by the suffix of the key starting at offset
+    suffixes: Vec<(u32, u32)>,
+}
+
+impl SuffixIndex {
+    pub(crate) fn new<'a>(keys: impl Iterator<Item = &'a String>) -> SuffixIndex {
+        let mut keys: Vec<Vec<char>> = keys.map(|k| k.chars().collect()).collect();
+        keys.sort();
+        let mut suffixes: Vec<(u32, u32)> = keys
+            .iter()
+            .enumerate()
+            .flat_map(|(k, key)| (0..key.len()).map(move |offset| (k as u32, offset as u32)))
+            .collect();
+        suffixes.sort_by(|a, b| Self::suffix_of(&keys, *a).cmp(Self::suffix_of(&keys, *b)));
+        SuffixIndex { keys, suffixes }
+    }
+
+    fn suffix_of(keys: &[Vec<char>], (key, offset): (u32, u32)) -> &[char] {
+        &keys[key as usize][offset as usize..]
+    }
+
+    /// Range of suffixes starting with `pattern`.
+    fn range(&self, pattern: &[char]) -> &[(u32, u32)] {
+        let start = self
+            .suffixes
+            .partition_point(|s| Self::suffix_of(&self.keys, *s).cmp(pattern) == Ordering::Less);
+        let len = self.suffixes[start..]
+            .partition_point(|s| Self::suffix_of(&self.keys, *s).starts_with(pattern));
+        &self.suffixes[start..start + len]
+    }
+
+    /// Keys containing `pattern` with the offsets of each occurrence, ordered by key.
+    /// At most `limit` keys are returned.
+    pub(crate) fn find_contains(&self, pattern: &str, limit: usize) -> Vec<(String, Vec<usize>)> {
+        let pattern: Vec<char> = pattern.chars().collect();
+        if pattern.is_empty() {
+            return vec![];
+        }
+        self.collect(self.range(&pattern).iter(), limit)
+    }
+
+    /// Keys ending with `pattern`, the offset is where the ending starts.
+    pub(crate) fn find_suffix(&self, pattern: &str, limit: usize) -> Vec<(String, Vec<usize>)> {
+        let pattern: Vec<char> = pattern.chars().collect();
+        if pattern.is_empty() {
+            return vec![];
+        }
+        let hits = self
+            .range(&pattern)
+            .iter()
+            .filter(|s| Self::suffix_of(&self.keys, **s).len() == pattern.len());
+        self.collect(hits, limit)
+    }
+
+    fn collect<'a>(
+        &self,
+        hits: impl Iterator<Item = &'a (u32, u32)>,
+        limit: usize,
+    ) -> Vec<(String, Vec<usize>)> {
+        let mut hits: Vec<(u32, u32)> = hits.cloned().collect();
+        // keys were sorted before the suffixes were, so key order is alphabetical order
+        hits.sort();
+        let mut result: Vec<(String, Vec<usize>)> = Vec::new();
+        let mut last_key = None;
+        for (key, offset) in hits {
+            if last_key != Some(key) {
+                if result.len() >= limit {
+                    break;
+                }
+                result.push((self.keys[key as usize].iter().collect(), Vec::new()));
+                last_key = Some(key);
+            }
+            result.last_mut().unwrap().1.push(offset as usize);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::suffix_index::SuffixIndex;
+
+    #[test]
+    fn contains_and_suffix() {
+        let keys: Vec<String> = ["כתבתם", "כתבת", "התכתבתם", "סידרתן"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let index = SuffixIndex::new(keys.iter());
+        assert_eq!(
+            index.find_suffix("תם", 15),
+            vec![
+                ("התכתבתם".to_string(), vec![5]),
+                ("כתבתם".to_string(), vec![3])
+            ]
+        );
+        assert_eq!(
+            index.find_contains("ת", 1),
+            vec![("התכתבתם".to_string(), vec![1, 3, 5])]
+        );
+        assert_eq!(index.find_contains("תכת", 15).len(), 1);
+        assert!(index.find_contains("", 15).is_empty());
+        assert!(index.find_suffix("כתב", 15).is_empty());
+    }
+}
@@ -0,0 +1,172 @@
+use regex::Regex;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::util::{normalize, unfinal};
+
+const NUMBERED_SLOTS: [char; 4] = ['1', '2', '3', '4'];
+const KTL_SLOTS: [char; 3] = ['ק', 'ט', 'ל'];
+const PAL_SLOTS: [char; 3] = ['פ', 'ע', 'ל'];
+
+/// A mishkal / binyan template such as "הִתְקַטֵּל" compiled to a regular expression where every
+/// root slot is a capture group.
+pub(crate) struct Template {
+    regex: Regex,
+    // root slot (0-based) of each capture group
+    slots: Vec<usize>,
+    pub(crate) vowelled: bool,
+}
+
+/// Marks that are not part of the spelling we compare: cantillation, meteg, and the shin/sin
+/// dots which canonical ordering places after the vowels of the letter.
+fn is_ignored_mark(c: char) -> bool {
+    ('\u{0591}'..='\u{05AF}').contains(&c) || matches!(c, '\u{05BD}' | '\u{05C1}' | '\u{05C2}')
+}
+
+fn is_niqqud(c: char) -> bool {
+    ('\u{05B0}'..='\u{05C7}').contains(&c)
+}
+
+/// Canonical vowelled spelling used on both sides of a vowelled template match.
+pub(crate) fn prepare_vowelled(val: &str) -> String {
+    val.nfd().filter(|c| !is_ignored_mark(*c)).collect()
+}
+
+/// Positions of the ק-ט-ל or פ-ע-ל slots: the last occurrence of the third letter, the last
+/// occurrence of the second one before it and of the first one before that, so that the
+/// ל of an infinitive prefix such as "לְהִתְפַּעֵל" stays a literal.
+fn word_slots(chars: &[char], letters: &[char; 3]) -> Option<Vec<(usize, usize)>> {
+    let mut end = chars.len();
+    let mut slots = Vec::new();
+    for slot in (0..letters.len()).rev() {
+        end = chars[..end].iter().rposition(|c| *c == letters[slot])?;
+        slots.push((end, slot));
+    }
+    slots.reverse();
+    Some(slots)
+}
+
+impl Template {
+    /// Root letters are the digits 1-4 when the template has any, every digit being a slot.
+    /// Otherwise they are ק-ט-ל, or פ-ע-ל, in that order, and other occurrences of those
+    /// letters are literals (see `word_slots`). Returns `None` for templates without slots.
+    pub(crate) fn compile(template: &str) -> Option<Template> {
+        let vowelled = template.chars().any(is_niqqud);
+        let text = if vowelled {
+            prepare_vowelled(template)
+        } else {
+            normalize(template)
+        };
+        let chars: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+        let slot_positions: Vec<(usize, usize)> =
+            if chars.iter().any(|c| NUMBERED_SLOTS.contains(c)) {
+                chars
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, c)| NUMBERED_SLOTS.iter().position(|s| s == c).map(|s| (i, s)))
+                    .collect()
+            } else {
+                word_slots(&chars, &KTL_SLOTS).or_else(|| word_slots(&chars, &PAL_SLOTS))?
+            };
+        let mut pattern = String::from("^");
+        let mut slots = Vec::new();
+        for (i, c) in chars.iter().enumerate() {
+            match slot_positions.iter().find(|(position, _)| *position == i) {
+                Some((_, slot)) => {
+                    pattern.push_str("([\u{05D0}-\u{05EA}])");
+                    slots.push(*slot);
+                }
+                None => pattern.push_str(&regex::escape(&c.to_string())),
+            }
+        }
+        pattern.push('$');
+        Some(Template {
+            regex: Regex::new(&pattern).ok()?,
+            slots,
+            vowelled,
+        })
+    }
+
+    /// Matches an already prepared spelling and returns the root letters bound to each slot.
+    /// A slot used more than once must bind the same letter every time.
+    pub(crate) fn bind(&self, text: &str) -> Option<Vec<char>> {
+        let captures = self.regex.captures(text)?;
+        let slot_count = self.slots.iter().max().map_or(0, |m| m + 1);
+        let mut bound: Vec<Option<char>> = vec![None; slot_count];
+        for (group, slot) in self.slots.iter().enumerate() {
+            let letter = unfinal(captures.get(group + 1)?.as_str().chars().next()?);
+            match bound[*slot] {
+                Some(previous) if previous != letter => return None,
+                _ => bound[*slot] = Some(letter),
+            }
+        }
+        bound.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::template::{prepare_vowelled, Template};
+    use crate::test_data::test_index;
+
+    #[test]
+    fn compile_and_bind() {
+        let template = Template::compile("הִתְקַטֵּל").unwrap();
+        assert!(template.vowelled);
+        assert_eq!(
+            template.bind(&prepare_vowelled("הִתְלַבֵּשׁ")),
+            Some(vec!['ל', 'ב', 'ש'])
+        );
+        let template = Template::compile("מ1ו23").unwrap();
+        assert!(!template.vowelled);
+        assert_eq!(template.bind("מסודר"), Some(vec!['ס', 'ד', 'ר']));
+        assert_eq!(template.bind("מסודרת"), None);
+        let template = Template::compile("התפעל").unwrap();
+        assert_eq!(template.bind("התלבש"), Some(vec!['ל', 'ב', 'ש']));
+        assert!(Template::compile("הלך").is_none());
+    }
+
+    #[test]
+    fn infinitive_templates_keep_the_prefix_lamed() {
+        let template = Template::compile("לְקַטֵּל").unwrap();
+        assert_eq!(
+            template.bind(&prepare_vowelled("לְסַדֵּר")),
+            Some(vec!['ס', 'ד', 'ר'])
+        );
+        assert_eq!(template.bind(&prepare_vowelled("סְסַדֵּר")), None);
+        let template = Template::compile("לְהַקְטִיל").unwrap();
+        assert_eq!(
+            template.bind(&prepare_vowelled("לְהַזְמִין")),
+            Some(vec!['ז', 'מ', 'נ'])
+        );
+        let template = Template::compile("להתפעל").unwrap();
+        assert_eq!(template.bind("להתלבש"), Some(vec!['ל', 'ב', 'ש']));
+        // lamed root letters are bound, not taken for the prefix
+        let template = Template::compile("לְהִתְקַטֵּל").unwrap();
+        assert_eq!(
+            template.bind(&prepare_vowelled("לְהִתְקַלֵּל")),
+            Some(vec!['ק', 'ל', 'ל'])
+        );
+    }
+
+    #[test]
+    fn search_template() {
+        let index = test_index();
+        let results = index.search_template("קִטַּלְתִּי", 15);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].word.url_id, "1420-lesader");
+        assert_eq!(results[0].root_letters, vec!["ס", "ד", "ר"]);
+        assert_eq!(results[0].matching_forms.len(), 1);
+
+        let results = index.search_template("ת12ו3", 15);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].word.url_id, "927-lichtov");
+        assert_eq!(results[0].matching_forms.len(), 2);
+        assert!(index.search_template("ללא", 15).is_empty());
+
+        let results = index.search_template("לקטל", 15);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].word.url_id, "1420-lesader");
+        assert_eq!(results[0].root_letters, vec!["ס", "ד", "ר"]);
+        assert_eq!(results[0].matching_forms[0].kind, 0);
+    }
+}
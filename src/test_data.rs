@@ -0,0 +1,364 @@
+use crate::word_dto::{Government, Possessor, WordData, WordForm};
+use crate::word_index::WordIndex;
+
+// (tense, person, number, gender, form, form_vowelled, transcription, meaning)
+type FormRow = (
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+);
+
+fn forms(rows: &[FormRow]) -> Vec<WordForm> {
+    rows.iter()
+        .map(
+            |(tense, person, number, gender, form, vowelled, transcription, meaning)| WordForm {
+                tense: tense.to_string(),
+                person: person.to_string(),
+                number: number.to_string(),
+                gender: gender.to_string(),
+                form: form.to_string(),
+                form_normalized: form.to_string(),
+                transcription: transcription.to_string(),
+                meaning: meaning.to_string(),
+                form_vowelled: Some(vowelled.to_string()),
+                irregular: false,
+                state: None,
+                possessor: None,
+            },
+        )
+        .collect()
+}
+
+fn government(preposition: &str, argument: &str, examples: &[&str]) -> Government {
+    Government {
+        preposition: preposition.to_string(),
+        argument: argument.to_string(),
+        examples: examples.iter().map(|e| e.to_string()).collect(),
+    }
+}
+
+#[rustfmt::skip]
+pub(crate) fn lichtov() -> WordData {
+    WordData {
+        url_id: "927-lichtov".to_string(),
+        word: "לכתוב".to_string(),
+        word_en: "to write (something)".to_string(),
+        word_normalized: "לכתוב".to_string(),
+        transcription: "lichtov".to_string(),
+        root: "כ - ת - ב".to_string(),
+        forms: forms(&[
+            ("present", "all", "singular", "m", "כותב", "כּוֹתֵב", "kotev", "(I/you/he) write(s)"),
+            ("present", "all", "singular", "f", "כותבת", "כּוֹתֶבֶת", "kotevet", "(I/you/she) write(s)"),
+            ("present", "all", "plural", "m", "כותבים", "כּוֹתְבִים", "kotvim", "(we/you/they) write"),
+            ("present", "all", "plural", "f", "כותבות", "כּוֹתְבוֹת", "kotvot", "(we/you/they) write"),
+            ("past", "1st", "singular", "all", "כתבתי", "כָּתַבְתִּי", "katavti", "I wrote"),
+            ("past", "1st", "plural", "all", "כתבנו", "כָּתַבְנוּ", "katavnu", "we wrote"),
+            ("past", "2nd", "singular", "m", "כתבת", "כָּתַבְתָּ", "katavta", "you wrote"),
+            ("past", "2nd", "singular", "f", "כתבת", "כָּתַבְתְּ", "katavt", "you wrote"),
+            ("past", "2nd", "plural", "m", "כתבתם", "כְּתַבְתֶּם", "ktavtem", "you wrote"),
+            ("past", "2nd", "plural", "f", "כתבתן", "כְּתַבְתֶּן", "ktavten", "you wrote"),
+            ("past", "3rd", "singular", "m", "כתב", "כָּתַב", "katav", "he wrote"),
+            ("past", "3rd", "singular", "f", "כתבה", "כָּתְבָה", "katva", "she wrote"),
+            ("past", "3rd", "plural", "all", "כתבו", "כָּתְבוּ", "katvu", "they wrote"),
+            ("future", "1st", "singular", "all", "אכתוב", "אֶכְתֹּב", "echtov", "I will write"),
+            ("future", "1st", "plural", "all", "נכתוב", "נִכְתֹּב", "nichtov", "we will write"),
+            ("future", "2nd", "singular", "m", "תכתוב", "תִּכְתֹּב", "tichtov", "you will write"),
+            ("future", "2nd", "singular", "f", "תכתבי", "תִּכְתְּבִי", "tichtevi", "you will write"),
+            ("future", "2nd", "plural", "m", "תכתבו", "תִּכְתְּבוּ", "tichtevu", "you will write"),
+            ("future", "2nd", "plural", "f", "תכתובנה", "תִּכְתֹּבְנָה", "tichtovna", "you will write"),
+            ("future", "3rd", "singular", "m", "יכתוב", "יִכְתֹּב", "yichtov", "he will write"),
+            ("future", "3rd", "singular", "f", "תכתוב", "תִּכְתֹּב", "tichtov", "she will write"),
+            ("future", "3rd", "plural", "m", "יכתבו", "יִכְתְּבוּ", "yichtevu", "they will write"),
+            ("future", "3rd", "plural", "f", "תכתובנה", "תִּכְתֹּבְנָה", "tichtovna", "they will write"),
+            ("imperative", "2nd", "singular", "m", "כתוב", "כְּתֹב", "ktov", "write!"),
+            ("imperative", "2nd", "singular", "f", "כתבי", "כִּתְבִי", "kitvi", "write!"),
+            ("imperative", "2nd", "plural", "m", "כתבו", "כִּתְבוּ", "kitvu", "write!"),
+            ("imperative", "2nd", "plural", "f", "כתובנה", "כְּתֹבְנָה", "ktovna", "write!"),
+        ]),
+        binyan: "PA'AL".to_string(),
+        passive: None,
+        passive_binyan: None,
+        gizrot: vec![],
+        synthetic: false,
+        irregular_cells: vec![],
+        infinitives: vec![],
+        part_of_speech: "verb".to_string(),
+        mishkal: None,
+        gender: None,
+        government: vec![
+            government("ל", "person", &["לכתוב לחבר"]),
+            government("את", "thing", &["לכתוב את המכתב"]),
+        ],
+    }
+}
+
+#[rustfmt::skip]
+pub(crate) fn lesader() -> WordData {
+    WordData {
+        url_id: "1420-lesader".to_string(),
+        word: "לסדר".to_string(),
+        word_en: "to arrange, to organize".to_string(),
+        word_normalized: "לסדר".to_string(),
+        transcription: "lesader".to_string(),
+        root: "ס - ד - ר".to_string(),
+        forms: forms(&[
+            ("present", "all", "singular", "m", "מסדר", "מְסַדֵּר", "mesader", "(I/you/he) arrange(s)"),
+            ("present", "all", "singular", "f", "מסדרת", "מְסַדֶּרֶת", "mesaderet", "(I/you/she) arrange(s)"),
+            ("present", "all", "plural", "m", "מסדרים", "מְסַדְּרִים", "mesadrim", "(we/you/they) arrange"),
+            ("present", "all", "plural", "f", "מסדרות", "מְסַדְּרוֹת", "mesadrot", "(we/you/they) arrange"),
+            ("past", "1st", "singular", "all", "סידרתי", "סִדַּרְתִּי", "sidarti", "I arranged"),
+            ("past", "1st", "plural", "all", "סידרנו", "סִדַּרְנוּ", "sidarnu", "we arranged"),
+            ("past", "2nd", "singular", "m", "סידרת", "סִדַּרְתָּ", "sidarta", "you arranged"),
+            ("past", "2nd", "singular", "f", "סידרת", "סִדַּרְתְּ", "sidart", "you arranged"),
+            ("past", "2nd", "plural", "m", "סידרתם", "סִדַּרְתֶּם", "sidartem", "you arranged"),
+            ("past", "2nd", "plural", "f", "סידרתן", "סִדַּרְתֶּן", "sidarten", "you arranged"),
+            ("past", "3rd", "singular", "m", "סידר", "סִדֵּר", "sider", "he arranged"),
+            ("past", "3rd", "singular", "f", "סידרה", "סִדְּרָה", "sidra", "she arranged"),
+            ("past", "3rd", "plural", "all", "סידרו", "סִדְּרוּ", "sidru", "they arranged"),
+            ("future", "1st", "singular", "all", "אסדר", "אֲסַדֵּר", "asader", "I will arrange"),
+            ("future", "1st", "plural", "all", "נסדר", "נְסַדֵּר", "nesader", "we will arrange"),
+            ("future", "2nd", "singular", "m", "תסדר", "תְּסַדֵּר", "tesader", "you will arrange"),
+            ("future", "2nd", "singular", "f", "תסדרי", "תְּסַדְּרִי", "tesadri", "you will arrange"),
+            ("future", "2nd", "plural", "m", "תסדרו", "תְּסַדְּרוּ", "tesadru", "you will arrange"),
+            ("future", "2nd", "plural", "f", "תסדרנה", "תְּסַדֵּרְנָה", "tesaderna", "you will arrange"),
+            ("future", "3rd", "singular", "m", "יסדר", "יְסַדֵּר", "yesader", "he will arrange"),
+            ("future", "3rd", "singular", "f", "תסדר", "תְּסַדֵּר", "tesader", "she will arrange"),
+            ("future", "3rd", "plural", "m", "יסדרו", "יְסַדְּרוּ", "yesadru", "they will arrange"),
+            ("future", "3rd", "plural", "f", "תסדרנה", "תְּסַדֵּרְנָה", "tesaderna", "they will arrange"),
+            ("imperative", "2nd", "singular", "m", "סדר", "סַדֵּר", "sader", "arrange!"),
+            ("imperative", "2nd", "singular", "f", "סדרי", "סַדְּרִי", "sadri", "arrange!"),
+            ("imperative", "2nd", "plural", "m", "סדרו", "סַדְּרוּ", "sadru", "arrange!"),
+            ("imperative", "2nd", "plural", "f", "סדרנה", "סַדֵּרְנָה", "saderna", "arrange!"),
+        ]),
+        binyan: "PI'EL".to_string(),
+        passive: Some(forms(&[
+            ("present", "all", "singular", "m", "מסודר", "מְסֻדָּר", "mesudar", "(am/are/is) arranged"),
+            ("present", "all", "singular", "f", "מסודרת", "מְסֻדֶּרֶת", "mesuderet", "(am/are/is) arranged"),
+            ("present", "all", "plural", "m", "מסודרים", "מְסֻדָּרִים", "mesudarim", "(are) arranged"),
+            ("present", "all", "plural", "f", "מסודרות", "מְסֻדָּרוֹת", "mesudarot", "(are) arranged"),
+            ("past", "1st", "singular", "all", "סודרתי", "סֻדַּרְתִּי", "sudarti", "I was arranged"),
+            ("past", "1st", "plural", "all", "סודרנו", "סֻדַּרְנוּ", "sudarnu", "we were arranged"),
+            ("past", "2nd", "singular", "m", "סודרת", "סֻדַּרְתָּ", "sudarta", "you were arranged"),
+            ("past", "2nd", "singular", "f", "סודרת", "סֻדַּרְתְּ", "sudart", "you were arranged"),
+            ("past", "2nd", "plural", "m", "סודרתם", "סֻדַּרְתֶּם", "sudartem", "you were arranged"),
+            ("past", "2nd", "plural", "f", "סודרתן", "סֻדַּרְתֶּן", "sudarten", "you were arranged"),
+            ("past", "3rd", "singular", "m", "סודר", "סֻדַּר", "sudar", "he was arranged"),
+            ("past", "3rd", "singular", "f", "סודרה", "סֻדְּרָה", "sudra", "she was arranged"),
+            ("past", "3rd", "plural", "all", "סודרו", "סֻדְּרוּ", "sudru", "they were arranged"),
+            ("future", "1st", "singular", "all", "אסודר", "אֲסֻדַּר", "asudar", "I will be arranged"),
+            ("future", "1st", "plural", "all", "נסודר", "נְסֻדַּר", "nesudar", "we will be arranged"),
+            ("future", "2nd", "singular", "m", "תסודר", "תְּסֻדַּר", "tesudar", "you will be arranged"),
+            ("future", "2nd", "singular", "f", "תסודרי", "תְּסֻדְּרִי", "tesudri", "you will be arranged"),
+            ("future", "2nd", "plural", "m", "תסודרו", "תְּסֻדְּרוּ", "tesudru", "you will be arranged"),
+            ("future", "2nd", "plural", "f", "תסודרנה", "תְּסֻדַּרְנָה", "tesudarna", "you will be arranged"),
+            ("future", "3rd", "singular", "m", "יסודר", "יְסֻדַּר", "yesudar", "he will be arranged"),
+            ("future", "3rd", "singular", "f", "תסודר", "תְּסֻדַּר", "tesudar", "she will be arranged"),
+            ("future", "3rd", "plural", "m", "יסודרו", "יְסֻדְּרוּ", "yesudru", "they will be arranged"),
+            ("future", "3rd", "plural", "f", "תסודרנה", "תְּסֻדַּרְנָה", "tesudarna", "they will be arranged"),
+        ])),
+        passive_binyan: Some("PU'AL".to_string()),
+        gizrot: vec![],
+        synthetic: false,
+        irregular_cells: vec![],
+        infinitives: vec![],
+        part_of_speech: "verb".to_string(),
+        mishkal: None,
+        gender: None,
+        government: vec![government("את", "thing", &["לסדר את החדר", "לסדר את הספרים"])],
+    }
+}
+
+// (state, number, possessor (person, number, gender), form, form_vowelled, transcription,
+// meaning), every form of a masculine noun
+type NounFormRow = (
+    Option<&'static str>,
+    &'static str,
+    Option<(&'static str, &'static str, &'static str)>,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+);
+
+fn noun_forms(rows: &[NounFormRow]) -> Vec<WordForm> {
+    rows.iter()
+        .map(
+            |(state, number, possessor, form, vowelled, transcription, meaning)| WordForm {
+                tense: String::new(),
+                person: String::new(),
+                number: number.to_string(),
+                gender: "m".to_string(),
+                form: form.to_string(),
+                form_normalized: form.to_string(),
+                transcription: transcription.to_string(),
+                meaning: meaning.to_string(),
+                form_vowelled: Some(vowelled.to_string()),
+                irregular: false,
+                state: state.map(String::from),
+                possessor: possessor.map(|(person, number, gender)| Possessor {
+                    person: person.to_string(),
+                    number: number.to_string(),
+                    gender: gender.to_string(),
+                }),
+            },
+        )
+        .collect()
+}
+
+#[rustfmt::skip]
+pub(crate) fn sefer() -> WordData {
+    WordData {
+        url_id: "6046-sefer".to_string(),
+        word: "ספר".to_string(),
+        word_en: "book".to_string(),
+        word_normalized: "ספר".to_string(),
+        transcription: "sefer".to_string(),
+        root: "ס - פ - ר".to_string(),
+        forms: noun_forms(&[
+            (Some("absolute"), "singular", None, "ספר", "סֵפֶר", "sefer", "book"),
+            (Some("absolute"), "plural", None, "ספרים", "סְפָרִים", "sfarim", "books"),
+            (Some("construct"), "singular", None, "ספר", "סֵפֶר", "sefer", "book of"),
+            (Some("construct"), "plural", None, "ספרי", "סִפְרֵי", "sifrei", "books of"),
+            (None, "singular", Some(("1st", "singular", "all")), "ספרי", "סִפְרִי", "sifri", "my book"),
+            (None, "singular", Some(("2nd", "singular", "m")), "ספרך", "סִפְרְךָ", "sifrecha", "your book"),
+            (None, "singular", Some(("2nd", "singular", "f")), "ספרך", "סִפְרֵךְ", "sifrech", "your book"),
+            (None, "singular", Some(("3rd", "singular", "m")), "ספרו", "סִפְרוֹ", "sifro", "his book"),
+            (None, "singular", Some(("3rd", "singular", "f")), "ספרה", "סִפְרָהּ", "sifra", "her book"),
+            (None, "singular", Some(("1st", "plural", "all")), "ספרנו", "סִפְרֵנוּ", "sifrenu", "our book"),
+            (None, "singular", Some(("2nd", "plural", "m")), "ספרכם", "סִפְרְכֶם", "sifrechem", "your book"),
+            (None, "singular", Some(("2nd", "plural", "f")), "ספרכן", "סִפְרְכֶן", "sifrechen", "your book"),
+            (None, "singular", Some(("3rd", "plural", "m")), "ספרם", "סִפְרָם", "sifram", "their book"),
+            (None, "singular", Some(("3rd", "plural", "f")), "ספרן", "סִפְרָן", "sifran", "their book"),
+            (None, "plural", Some(("1st", "singular", "all")), "ספריי", "סְפָרַי", "sfaray", "my books"),
+            (None, "plural", Some(("2nd", "singular", "m")), "ספריך", "סְפָרֶיךָ", "sfarecha", "your books"),
+            (None, "plural", Some(("2nd", "singular", "f")), "ספרייך", "סְפָרַיִךְ", "sfarayich", "your books"),
+            (None, "plural", Some(("3rd", "singular", "m")), "ספריו", "סְפָרָיו", "sfarav", "his books"),
+            (None, "plural", Some(("3rd", "singular", "f")), "ספריה", "סְפָרֶיהָ", "sfareha", "her books"),
+            (None, "plural", Some(("1st", "plural", "all")), "ספרינו", "סְפָרֵינוּ", "sfareinu", "our books"),
+            (None, "plural", Some(("2nd", "plural", "m")), "ספריכם", "סִפְרֵיכֶם", "sifreichem", "your books"),
+            (None, "plural", Some(("2nd", "plural", "f")), "ספריכן", "סִפְרֵיכֶן", "sifreichen", "your books"),
+            (None, "plural", Some(("3rd", "plural", "m")), "ספריהם", "סִפְרֵיהֶם", "sifreihem", "their books"),
+            (None, "plural", Some(("3rd", "plural", "f")), "ספריהן", "סִפְרֵיהֶן", "sifreihen", "their books"),
+        ]),
+        binyan: String::new(),
+        passive: None,
+        passive_binyan: None,
+        gizrot: vec![],
+        synthetic: false,
+        irregular_cells: vec![],
+        infinitives: vec![],
+        part_of_speech: "noun".to_string(),
+        mishkal: Some("CeCeC".to_string()),
+        gender: Some("m".to_string()),
+        government: vec![],
+    }
+}
+
+// (state, number, gender, form, form_vowelled, transcription, meaning)
+type AdjectiveFormRow = (
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+);
+
+fn adjective_forms(rows: &[AdjectiveFormRow]) -> Vec<WordForm> {
+    rows.iter()
+        .map(
+            |(state, number, gender, form, vowelled, transcription, meaning)| WordForm {
+                tense: String::new(),
+                person: String::new(),
+                number: number.to_string(),
+                gender: gender.to_string(),
+                form: form.to_string(),
+                form_normalized: form.to_string(),
+                transcription: transcription.to_string(),
+                meaning: meaning.to_string(),
+                form_vowelled: Some(vowelled.to_string()),
+                irregular: false,
+                state: Some(state.to_string()),
+                possessor: None,
+            },
+        )
+        .collect()
+}
+
+#[rustfmt::skip]
+pub(crate) fn sadir() -> WordData {
+    WordData {
+        url_id: "4893-sadir".to_string(),
+        word: "סדיר".to_string(),
+        word_en: "regular, orderly".to_string(),
+        word_normalized: "סדיר".to_string(),
+        transcription: "sadir".to_string(),
+        root: "ס - ד - ר".to_string(),
+        forms: adjective_forms(&[
+            ("absolute", "singular", "m", "סדיר", "סְדִיר", "sadir", "regular, orderly"),
+            ("absolute", "singular", "f", "סדירה", "סְדִירָה", "sdira", "regular, orderly"),
+            ("absolute", "plural", "m", "סדירים", "סְדִירִים", "sdirim", "regular, orderly"),
+            ("absolute", "plural", "f", "סדירות", "סְדִירוֹת", "sdirot", "regular, orderly"),
+            ("construct", "singular", "m", "סדיר", "סְדִיר", "sdir", "regular in"),
+            ("construct", "singular", "f", "סדירת", "סְדִירַת", "sdirat", "regular in"),
+            ("construct", "plural", "m", "סדירי", "סְדִירֵי", "sdirei", "regular in"),
+            ("construct", "plural", "f", "סדירות", "סְדִירוֹת", "sdirot", "regular in"),
+        ]),
+        binyan: String::new(),
+        passive: None,
+        passive_binyan: None,
+        gizrot: vec![],
+        synthetic: false,
+        irregular_cells: vec![],
+        infinitives: vec![],
+        part_of_speech: "adjective".to_string(),
+        mishkal: Some("CaCiC".to_string()),
+        gender: None,
+        government: vec![],
+    }
+}
+
+#[rustfmt::skip]
+pub(crate) fn et() -> WordData {
+    WordData {
+        url_id: "3055-et".to_string(),
+        word: "את".to_string(),
+        word_en: "(marks the definite direct object)".to_string(),
+        word_normalized: "את".to_string(),
+        transcription: "et".to_string(),
+        root: String::new(),
+        forms: forms(&[
+            ("", "1st", "singular", "all", "אותי", "אוֹתִי", "oti", "me"),
+            ("", "2nd", "singular", "m", "אותך", "אוֹתְךָ", "otcha", "you"),
+            ("", "2nd", "singular", "f", "אותך", "אוֹתָךְ", "otach", "you"),
+            ("", "3rd", "singular", "m", "אותו", "אוֹתוֹ", "oto", "him"),
+            ("", "3rd", "singular", "f", "אותה", "אוֹתָהּ", "ota", "her"),
+            ("", "1st", "plural", "all", "אותנו", "אוֹתָנוּ", "otanu", "us"),
+            ("", "2nd", "plural", "m", "אתכם", "אֶתְכֶם", "etchem", "you"),
+            ("", "2nd", "plural", "f", "אתכן", "אֶתְכֶן", "etchen", "you"),
+            ("", "3rd", "plural", "m", "אותם", "אוֹתָם", "otam", "them"),
+            ("", "3rd", "plural", "f", "אותן", "אוֹתָן", "otan", "them"),
+        ]),
+        binyan: String::new(),
+        passive: None,
+        passive_binyan: None,
+        gizrot: vec![],
+        synthetic: false,
+        irregular_cells: vec![],
+        infinitives: vec![],
+        part_of_speech: "preposition".to_string(),
+        mishkal: None,
+        gender: None,
+        government: vec![],
+    }
+}
+
+pub(crate) fn test_index() -> WordIndex {
+    WordIndex::build(vec![lichtov(), lesader(), sefer(), sadir()])
+}
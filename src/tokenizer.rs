@@ -0,0 +1,57 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A word of the input text with its position in bytes and in characters.
+pub(crate) struct Token<'a> {
+    pub(crate) text: &'a str,
+    pub(crate) byte_start: usize,
+    pub(crate) char_start: usize,
+}
+
+impl Token<'_> {
+    pub(crate) fn byte_end(&self) -> usize {
+        self.byte_start + self.text.len()
+    }
+
+    pub(crate) fn char_end(&self) -> usize {
+        self.char_start + self.text.chars().count()
+    }
+}
+
+/// Splits text into words following Unicode word segmentation (UAX #29): niqqud stays with
+/// its letter, geresh and gershayim inside a word ("צה״ל") do not split it, while the maqaf
+/// and any other punctuation separate words and are dropped.
+pub(crate) fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut char_pos = 0;
+    let mut byte_pos = 0;
+    for (byte_start, segment) in text.split_word_bound_indices() {
+        char_pos += text[byte_pos..byte_start].chars().count();
+        byte_pos = byte_start;
+        if segment.chars().any(char::is_alphanumeric) {
+            tokens.push(Token {
+                text: segment,
+                byte_start,
+                char_start: char_pos,
+            });
+        }
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tokenizer::tokenize;
+
+    #[test]
+    fn tokenize_hebrew() {
+        let tokens = tokenize("בֵּית־סֵפֶר, צה״ל (ג׳ינס) ok!");
+        let words: Vec<&str> = tokens.iter().map(|t| t.text).collect();
+        assert_eq!(words, vec!["בֵּית", "סֵפֶר", "צה״ל", "ג׳ינס", "ok"]);
+        assert_eq!(tokens[1].char_start, 6);
+        assert_eq!(tokens[1].byte_start, "בֵּית־".len());
+        assert_eq!(
+            tokens[4].char_end(),
+            "בֵּית־סֵפֶר, צה״ל (ג׳ינס) ok".chars().count()
+        );
+    }
+}
@@ -8,3 +8,48 @@ pub(crate) fn normalize(val: &str) -> String {
         .filter(|c| !is_combining_mark(*c)) // Filter out combining marks
         .collect::<String>()
 }
+
+pub(crate) fn is_hebrew_letter(c: char) -> bool {
+    ('\u{05D0}'..='\u{05EA}').contains(&c)
+}
+
+pub(crate) fn unfinal(c: char) -> char {
+    match c {
+        'ך' => 'כ',
+        'ם' => 'מ',
+        'ן' => 'נ',
+        'ף' => 'פ',
+        'ץ' => 'צ',
+        _ => c,
+    }
+}
+
+/// Reduces a root such as "כ - ת - ב" or "ש-מ-ר" to its bare letters ("כתב", "שמר"),
+/// final letters are replaced with their regular forms.
+pub(crate) fn normalize_root(root: &str) -> String {
+    normalize(root)
+        .chars()
+        .filter(|c| is_hebrew_letter(*c))
+        .map(unfinal)
+        .collect()
+}
+
+pub(crate) fn escape_html(val: &str) -> String {
+    let mut escaped = String::with_capacity(val.len());
+    for c in val.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Number of terminal columns taken by the text: combining marks such as niqqud take none.
+pub(crate) fn display_width(val: &str) -> usize {
+    val.chars().filter(|c| !is_combining_mark(*c)).count()
+}
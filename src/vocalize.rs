@@ -0,0 +1,113 @@
+use crate::tokenizer::tokenize;
+use crate::word_dto::{SearchResult, Vocalization, VocalizationStatus, VocalizedToken};
+
+/// Distinct spellings of the readings found for a word: the vowelled spelling of each matched
+/// form, or its plain spelling when the dataset has no niqqud for it. A result without
+/// matched forms reads as its lemma.
+fn reading_candidates(results: &[SearchResult]) -> Vec<String> {
+    let mut candidates: Vec<String> = Vec::new();
+    for result in results {
+        if result.matching_forms.is_empty() && !candidates.contains(&result.word.word) {
+            candidates.push(result.word.word.clone());
+        }
+        for matched in &result.matching_forms {
+            let candidate = match result.word.matched_form(matched) {
+                Some(form) => form
+                    .form_vowelled
+                    .clone()
+                    .filter(|v| !v.is_empty())
+                    .unwrap_or_else(|| form.form.clone()),
+                None => result.word.word.clone(),
+            };
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        }
+    }
+    candidates
+}
+
+/// Replaces every word with a single reading by its vowelled spelling, everything else in the
+/// text (punctuation, spacing, unknown and ambiguous words) is copied unchanged. Words are
+/// unknown only when the lookup finds nothing, readings without niqqud count as readings.
+pub(crate) fn vocalize(text: &str, lookup: impl Fn(&str) -> Vec<SearchResult>) -> Vocalization {
+    let mut output = String::with_capacity(text.len() * 2);
+    let mut tokens = Vec::new();
+    let mut copied_to = 0;
+    for token in tokenize(text) {
+        output.push_str(&text[copied_to..token.byte_start]);
+        copied_to = token.byte_end();
+        let candidates = reading_candidates(&lookup(token.text));
+        let status = match candidates.len() {
+            0 => VocalizationStatus::UNKNOWN,
+            1 => VocalizationStatus::VOCALIZED,
+            _ => VocalizationStatus::AMBIGUOUS,
+        };
+        if status == VocalizationStatus::VOCALIZED {
+            output.push_str(&candidates[0]);
+        } else {
+            output.push_str(token.text);
+        }
+        tokens.push(VocalizedToken {
+            token: token.text.to_string(),
+            char_start: token.char_start,
+            char_end: token.char_end(),
+            status,
+            candidates,
+        });
+    }
+    output.push_str(&text[copied_to..]);
+    Vocalization {
+        text: output,
+        tokens,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_data::{lichtov, test_index};
+    use crate::word_dto::VocalizationStatus;
+    use crate::word_index::WordIndex;
+
+    #[test]
+    fn vocalize_text() {
+        let index = test_index();
+        let vocalization = index.vocalize("כתבתי מכתב, וסידרת אותו; כתבו!");
+        assert_eq!(vocalization.text, "כָּתַבְתִּי מכתב, וסידרת אותו; כתבו!");
+        let statuses: Vec<VocalizationStatus> =
+            vocalization.tokens.iter().map(|t| t.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                VocalizationStatus::VOCALIZED,
+                VocalizationStatus::UNKNOWN,
+                VocalizationStatus::UNKNOWN,
+                VocalizationStatus::UNKNOWN,
+                VocalizationStatus::AMBIGUOUS,
+            ]
+        );
+        assert_eq!(vocalization.tokens[4].candidates, vec!["כָּתְבוּ", "כִּתְבוּ"]);
+
+        let vocalization = index.vocalize("סידרת");
+        assert_eq!(vocalization.tokens[0].candidates, vec!["סִדַּרְתָּ", "סִדַּרְתְּ"]);
+    }
+
+    #[test]
+    fn readings_without_niqqud() {
+        let mut word = lichtov();
+        for form in word.forms.iter_mut() {
+            if form.form == "כתב" || form.tense == "imperative" {
+                form.form_vowelled = None;
+            }
+        }
+        let index = WordIndex::build(vec![word]);
+        let vocalization = index.vocalize("כתב כתבו");
+        // known, with a single reading that has no niqqud
+        assert_eq!(vocalization.tokens[0].status, VocalizationStatus::VOCALIZED);
+        assert_eq!(vocalization.tokens[0].candidates, vec!["כתב"]);
+        // one vowelled reading and one without niqqud are still two readings
+        assert_eq!(vocalization.tokens[1].status, VocalizationStatus::AMBIGUOUS);
+        assert_eq!(vocalization.tokens[1].candidates, vec!["כָּתְבוּ", "כתבו"]);
+        assert_eq!(vocalization.text, "כתב כתבו");
+    }
+}
@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+
+use crate::gizra::classify_root;
 #[cfg(feature = "wasm-support")]
 use wasm_bindgen::prelude::wasm_bindgen;
 
@@ -14,7 +16,27 @@ pub struct WordForm {
     pub transcription: String,
     pub meaning: String,
     pub form_vowelled: Option<String>,
+    /// Differs from the regular paradigm of the binyan, see `WordData::irregular_cells`.
+    #[serde(default)]
+    pub irregular: bool,
+    /// "absolute" or "construct" for noun and adjective forms; None for verb forms and
+    /// possessive forms.
+    #[serde(default)]
+    pub state: Option<String>,
+    /// Owner of a noun form with a possessive suffix ("ספריו" is 3rd singular masculine).
+    #[serde(default)]
+    pub possessor: Option<Possessor>,
+}
+
+/// Person, number and gender of a possessive suffix.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Possessor {
+    pub person: String,
+    pub number: String,
+    pub gender: String,
 }
+
 #[cfg_attr(feature = "wasm-support", wasm_bindgen)]
 impl WordForm {
     #[cfg_attr(feature = "wasm-support", wasm_bindgen(constructor))]
@@ -39,6 +61,9 @@ impl WordForm {
             transcription,
             meaning,
             form_vowelled,
+            irregular: false,
+            state: None,
+            possessor: None,
         }
     }
 }
@@ -56,6 +81,51 @@ pub struct WordData {
     pub binyan: String,
     pub passive: Option<Vec<WordForm>>,
     pub passive_binyan: Option<String>,
+    /// Weak root classes of `root`, see `gizra::classify_root`.
+    #[serde(default)]
+    pub gizrot: Vec<String>,
+    /// Generated by `WordData::conjugate` rather than taken from the dataset.
+    #[serde(default)]
+    pub synthetic: bool,
+    /// Cells whose form differs from the regular paradigm, filled in by `WordIndex::build`.
+    /// Keys look like "past.3rd.singular.m", passive cells start with "passive." and the
+    /// infinitive is "infinitive".
+    #[serde(default)]
+    pub irregular_cells: Vec<String>,
+    /// The infinitive and its variants, see `infinitive::infinitive_forms`; `MatchedForm`s of
+    /// kind 0 index into it. Filled in by `WordIndex::build`.
+    #[serde(default)]
+    pub infinitives: Vec<WordForm>,
+    /// "verb", "noun", "adjective" or "preposition". Other words than verbs have an empty
+    /// `binyan` and no tenses: the `forms` of nouns and adjectives carry a `state` or a
+    /// `possessor` instead, the gender of adjective forms is the one they agree with and the
+    /// person, number and gender of preposition forms those of their pronominal suffix.
+    #[serde(default = "verb")]
+    pub part_of_speech: String,
+    /// Noun or adjective pattern such as "miCCaC".
+    #[serde(default)]
+    pub mishkal: Option<String>,
+    /// Grammatical gender of a noun, "m" or "f".
+    #[serde(default)]
+    pub gender: Option<String>,
+    /// Prepositions the verb takes its objects with.
+    #[serde(default)]
+    pub government: Vec<Government>,
+}
+
+/// A preposition a verb governs and what it introduces: "ל" and "person" for "לחכות לחבר".
+/// `preposition` is "את" for a definite direct object.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Government {
+    pub preposition: String,
+    /// "person", "thing" or "clause".
+    pub argument: String,
+    pub examples: Vec<String>,
+}
+
+fn verb() -> String {
+    "verb".to_string()
 }
 
 #[cfg_attr(feature = "wasm-support", wasm_bindgen)]
@@ -73,6 +143,7 @@ impl WordData {
         passive: Option<Vec<WordForm>>,
         passive_binyan: Option<String>,
     ) -> WordData {
+        let gizrot = classify_root(&root);
         WordData {
             url_id,
             word,
@@ -84,6 +155,59 @@ impl WordData {
             binyan,
             passive,
             passive_binyan,
+            gizrot,
+            synthetic: false,
+            irregular_cells: vec![],
+            infinitives: vec![],
+            part_of_speech: verb(),
+            mishkal: None,
+            gender: None,
+            government: vec![],
+        }
+    }
+}
+
+impl WordData {
+    pub(crate) fn is_verb(&self) -> bool {
+        self.part_of_speech == "verb"
+    }
+
+    /// The infinitive as a `WordForm`, person, number and gender are left empty.
+    pub(crate) fn infinitive_form(&self) -> WordForm {
+        if let Some(infinitive) = self.infinitives.first() {
+            return infinitive.clone();
+        }
+        WordForm {
+            tense: "infinitive".to_string(),
+            person: String::new(),
+            number: String::new(),
+            gender: String::new(),
+            form: self.word.clone(),
+            form_normalized: self.word_normalized.clone(),
+            transcription: self.transcription.clone(),
+            meaning: self.word_en.clone(),
+            form_vowelled: None,
+            irregular: false,
+            state: None,
+            possessor: None,
+        }
+    }
+
+    /// The lemma as an uninflected `WordForm`, for words whose forms all carry a suffix.
+    pub(crate) fn lemma_form(&self) -> WordForm {
+        WordForm {
+            tense: String::new(),
+            ..self.infinitive_form()
+        }
+    }
+
+    /// The form a `MatchedForm` points to.
+    pub(crate) fn matched_form(&self, matched: &MatchedForm) -> Option<&WordForm> {
+        match matched.kind {
+            0 => self.infinitives.get(matched.index),
+            1 => self.forms.get(matched.index),
+            2 => self.passive.as_ref()?.get(matched.index),
+            _ => None,
         }
     }
 }
@@ -95,6 +219,8 @@ pub struct SearchResult {
     pub matching_forms: Vec<MatchedForm>,
 }
 
+/// A form of a word, `kind` is a `FormKind`: `index` points into `infinitives`, `forms` or
+/// `passive`.
 #[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
 #[derive(Serialize, Deserialize, Clone)]
 pub struct MatchedForm {
@@ -102,12 +228,205 @@ pub struct MatchedForm {
     pub kind: usize,
 }
 
+/// Result of a template search: the forms matching the template and the root letters
+/// its slots were bound to.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TemplateMatch {
+    pub word: WordData,
+    pub matching_forms: Vec<MatchedForm>,
+    pub root_letters: Vec<String>,
+}
+
+/// A normalized form found by substring or suffix search, `offsets` are the character
+/// positions where the searched text occurs in `form`.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SubstringMatch {
+    pub form: String,
+    pub offsets: Vec<usize>,
+    pub results: Vec<SearchResult>,
+}
+
+/// A word of an analyzed text. Offsets are given both in bytes (for Rust strings) and in
+/// characters, i.e. Unicode scalar values as counted by `str::chars`. JS strings index by
+/// UTF-16 code unit instead, which only agrees with the character offsets while the text
+/// has no characters outside the Basic Multilingual Plane (emoji, for instance).
+/// `known` is false when no analysis was found.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AnalyzedToken {
+    pub token: String,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub char_start: usize,
+    pub char_end: usize,
+    pub known: bool,
+    pub analyses: Vec<SearchResult>,
+}
+
+/// One reading of a glossed word: the lemma it belongs to and the matched form.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GlossReading {
+    pub url_id: String,
+    pub lemma: String,
+    pub form: String,
+    pub transcription: String,
+    pub meaning: String,
+    pub tag: String,
+}
+
+/// A word of the glossed text with every reading found for it, `trailing` keeps the
+/// punctuation and spacing up to the next word.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GlossToken {
+    pub token: String,
+    pub trailing: String,
+    pub readings: Vec<GlossReading>,
+}
+
+/// Interlinear gloss of a text, see `WordIndex::gloss`.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Gloss {
+    pub leading: String,
+    pub tokens: Vec<GlossToken>,
+}
+
+#[cfg_attr(feature = "wasm-support", wasm_bindgen)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum VocalizationStatus {
+    VOCALIZED = 0,
+    AMBIGUOUS = 1,
+    UNKNOWN = 2,
+}
+
+/// A word of a vocalized text. `candidates` holds the distinct spellings of its readings,
+/// vowelled when the dataset has the niqqud; ambiguous and unknown words are left as they
+/// were in the input.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VocalizedToken {
+    pub token: String,
+    pub char_start: usize,
+    pub char_end: usize,
+    pub status: VocalizationStatus,
+    pub candidates: Vec<String>,
+}
+
+/// Result of `WordIndex::vocalize`: the text with niqqud added and the status of every word.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Vocalization {
+    pub text: String,
+    pub tokens: Vec<VocalizedToken>,
+}
+
+/// A cell of a conjugation table. A form shared by both genders is a single cell with
+/// gender "all" spanning the masculine and feminine columns.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ConjugationCell {
+    pub person: String,
+    pub number: String,
+    pub gender: String,
+    pub form: WordForm,
+}
+
+/// The cells of one tense, ordered by person, then number, then gender.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ConjugationRow {
+    pub tense: String,
+    pub cells: Vec<ConjugationCell>,
+}
+
+/// The forms of one binyan laid out by tense: present, past, future and imperative rows,
+/// the infinitive is kept apart (passive binyanim have none).
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ConjugationTable {
+    pub url_id: String,
+    pub binyan: String,
+    pub infinitive: Option<WordForm>,
+    pub rows: Vec<ConjugationRow>,
+}
+
+/// Conjugation of a verb, see `WordData::conjugation`.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Conjugation {
+    pub active: ConjugationTable,
+    pub passive: Option<ConjugationTable>,
+    /// The verb's `WordData::government`, rendered after the tables.
+    #[serde(default)]
+    pub government: Vec<Government>,
+}
+
+/// What the conjugation table renderers put in a cell: the vowelled or the plain spelling,
+/// the transcription as ruby text (HTML only) or on its own line, and optionally the meaning.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct RenderOptions {
+    pub vowelled: bool,
+    pub ruby: bool,
+    pub meaning: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        RenderOptions {
+            vowelled: true,
+            ruby: true,
+            meaning: false,
+        }
+    }
+}
+
+#[cfg_attr(feature = "wasm-support", wasm_bindgen)]
+impl RenderOptions {
+    #[cfg_attr(feature = "wasm-support", wasm_bindgen(constructor))]
+    pub fn new(vowelled: bool, ruby: bool, meaning: bool) -> RenderOptions {
+        RenderOptions {
+            vowelled,
+            ruby,
+            meaning,
+        }
+    }
+}
+
+/// One reading of a form given to `WordIndex::transform` and the forms it turns into.
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Transformation {
+    pub url_id: String,
+    pub lemma: String,
+    pub source: WordForm,
+    pub forms: Vec<WordForm>,
+}
+
+/// A guessed analysis of a word missing from the dataset: "probably a form of `root` in
+/// `binyan`". `forms` are the regular forms of that root spelled like the word, `url_ids`
+/// the dataset verbs sharing the root (empty when no verb has it).
+#[cfg_attr(feature = "wasm-support", wasm_bindgen(getter_with_clone))]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RootGuess {
+    pub root: String,
+    pub binyan: String,
+    pub score: f32,
+    pub forms: Vec<WordForm>,
+    pub url_ids: Vec<String>,
+}
+
+/// Values of `MatchedForm.kind`.
 #[cfg_attr(feature = "wasm-support", wasm_bindgen)]
 #[derive(Serialize, Deserialize, Clone)]
 pub enum FormKind {
     INFINITIVE = 0,
-    PASSIVE = 1,
-    ACTIVE = 2,
+    ACTIVE = 1,
+    PASSIVE = 2,
 }
 
 #[cfg_attr(feature = "wasm-support", wasm_bindgen)]
@@ -1,16 +1,37 @@
 use std::collections::{HashMap, HashSet};
 
 use prost::Message;
-use regex::Regex;
+use regex::{Regex, RegexBuilder};
 use rmp_serde::decode::Error;
 use rmp_serde::from_read;
 #[cfg(feature = "wasm-support")]
 use wasm_bindgen::prelude::wasm_bindgen;
 
+use crate::english::normalize_en;
+use crate::features::{Features, InflectError};
+use crate::gizra::classify_root;
+use crate::gloss::build_gloss;
+use crate::guess::guess_roots;
+use crate::infinitive::infinitive_forms;
+use crate::irregular::mark_irregular;
+use crate::lemmatizer::{
+    clitic_splits, defective_spelling, vowel_weight, CLITIC_WEIGHT, PASSIVE_WEIGHT, VARIANT_WEIGHT,
+};
 use crate::prefix_tree::Trie;
 use crate::proto::{convert_pb_to_dto, WORDS_PB};
-use crate::util::normalize;
-use crate::word_dto::{MatchedForm, SearchResult, WordData};
+use crate::query::{all_form_refs, FormRef, Postings, Query};
+use crate::query_parser::{parse_query, ParseError, QueryExpr};
+use crate::suffix_index::SuffixIndex;
+use crate::template::{prepare_vowelled, Template};
+use crate::tokenizer::tokenize;
+use crate::util::{normalize, normalize_root};
+use crate::vocalize::vocalize;
+use crate::word_dto::{
+    AnalyzedToken, Conjugation, Gloss, MatchedForm, RootGuess, SearchResult, SubstringMatch,
+    TemplateMatch, Transformation, Vocalization, WordData, WordForm,
+};
+
+const MAX_REGEX_SIZE: usize = 1 << 20;
 
 #[cfg_attr(feature = "wasm-support", wasm_bindgen)]
 pub struct WordIndex {
@@ -19,6 +40,10 @@ pub struct WordIndex {
     roots_index: HashMap<String, HashSet<String>>,
     prefix_tree: Trie,
     prefix_tree_en: Trie,
+    postings: Postings,
+    suffix_index: SuffixIndex,
+    // defective spelling (no matres lectionis) -> normalized forms spelled that way
+    variant_index: HashMap<String, HashSet<String>>,
 }
 
 impl WordIndex {
@@ -26,6 +51,189 @@ impl WordIndex {
         let my_data: Vec<WordData> = from_read(data)?;
         Ok(my_data)
     }
+
+    /// Lemmas a form may belong to as (lemma, url_id, score), best first. A score of 1.0 is an
+    /// exact match of an active form; passive forms, pointed tokens with different niqqud,
+    /// spellings that only match without matres lectionis and tokens read with proclitics
+    /// ("וכשכתבתי") score lower. The dataset has no frequency information, equal scores are
+    /// ordered by url_id.
+    pub fn lemmatize(&self, form: &str) -> Vec<(String, String, f32)> {
+        let form_norm = normalize(form);
+        let mut candidates = vec![(form_norm.clone(), 1.0)];
+        candidates.extend(
+            clitic_splits(&form_norm)
+                .into_iter()
+                .map(|(_, rest)| (rest, CLITIC_WEIGHT)),
+        );
+        let mut scores: HashMap<String, f32> = HashMap::new();
+        for (candidate, weight) in candidates {
+            let exact = self.index.contains_key(&candidate);
+            let keys: Vec<(String, f32)> = if exact {
+                vec![(candidate.clone(), weight)]
+            } else {
+                self.variant_index
+                    .get(&defective_spelling(&candidate))
+                    .into_iter()
+                    .flatten()
+                    .map(|key| (key.clone(), weight * VARIANT_WEIGHT))
+                    .collect()
+            };
+            for (key, weight) in keys {
+                for id in self.index.get(&key).into_iter().flatten() {
+                    let word_data = self.data.get(id).unwrap();
+                    for matched in WordIndex::matching_forms_inner(word_data, &key) {
+                        let mut score = weight;
+                        if matched.kind == 2 {
+                            score *= PASSIVE_WEIGHT;
+                        }
+                        if form != form_norm && candidate == form_norm {
+                            let vowelled = word_data
+                                .matched_form(&matched)
+                                .and_then(|f| f.form_vowelled.as_deref());
+                            score *= vowel_weight(form, vowelled);
+                        }
+                        let best = scores.entry(id.clone()).or_insert(0.0);
+                        *best = best.max(score);
+                    }
+                }
+            }
+        }
+        let mut lemmas: Vec<(String, String, f32)> = scores
+            .into_iter()
+            .map(|(id, score)| (self.data.get(&id).unwrap().word.clone(), id, score))
+            .collect();
+        lemmas.sort_by(|a, b| b.2.total_cmp(&a.2).then_with(|| a.1.cmp(&b.1)));
+        lemmas
+    }
+
+    /// `lemmatize` for a stream of tokens, repeated tokens are only analysed once.
+    pub fn lemmatize_batch<S: AsRef<str>>(&self, tokens: &[S]) -> Vec<Vec<(String, String, f32)>> {
+        let mut cache: HashMap<&str, Vec<(String, String, f32)>> = HashMap::new();
+        tokens
+            .iter()
+            .map(|token| {
+                cache
+                    .entry(token.as_ref())
+                    .or_insert_with(|| self.lemmatize(token.as_ref()))
+                    .clone()
+            })
+            .collect()
+    }
+
+    /// Forms of a word with the given features, the word is given by url_id or by its
+    /// infinitive (every homograph is inflected then). Unset features match anything, so
+    /// `Features::default()` returns all forms: the infinitive, active and passive.
+    pub fn inflect(
+        &self,
+        url_id_or_lemma: &str,
+        features: &Features,
+    ) -> Result<Vec<WordForm>, InflectError> {
+        let words: Vec<&WordData> = match self.data.get(url_id_or_lemma) {
+            Some(word) => vec![word],
+            None => {
+                let lemma = normalize(url_id_or_lemma);
+                let mut words: Vec<&WordData> = self
+                    .index
+                    .get(&lemma)
+                    .into_iter()
+                    .flatten()
+                    .map(|id| self.data.get(id).unwrap())
+                    .filter(|word| word.word_normalized == lemma)
+                    .collect();
+                words.sort_by(|a, b| a.url_id.cmp(&b.url_id));
+                words
+            }
+        };
+        if words.is_empty() {
+            return Err(InflectError::UnknownWord(url_id_or_lemma.to_string()));
+        }
+        let forms: Vec<WordForm> = words
+            .iter()
+            .flat_map(|word| features.select(word))
+            .map(|(_, form)| form)
+            .collect();
+        if forms.is_empty() {
+            return Err(InflectError::NoSuchForm {
+                word: url_id_or_lemma.to_string(),
+                features: features.to_ud(),
+            });
+        }
+        Ok(forms)
+    }
+
+    /// Turns a form into the one with `target` features, keeping the features `target`
+    /// leaves unset: "כתבתי" with tense future gives "אכתוב". Every reading of an ambiguous
+    /// form gives its own `Transformation`, readings without a matching form are left out.
+    pub fn transform(
+        &self,
+        form: &str,
+        target: &Features,
+    ) -> Result<Vec<Transformation>, InflectError> {
+        let results = self.get(form);
+        if results.is_empty() {
+            return Err(InflectError::UnknownWord(form.to_string()));
+        }
+        let mut transformations = Vec::new();
+        for result in &results {
+            let word = &result.word;
+            for matched in &result.matching_forms {
+                let Some(source) = Features::of(word, matched) else {
+                    continue;
+                };
+                let forms: Vec<WordForm> = source
+                    .overridden_by(target)
+                    .select(word)
+                    .into_iter()
+                    .map(|(_, form)| form)
+                    .collect();
+                if forms.is_empty() {
+                    continue;
+                }
+                transformations.push(Transformation {
+                    url_id: word.url_id.clone(),
+                    lemma: word.word.clone(),
+                    source: word
+                        .matched_form(matched)
+                        .cloned()
+                        .unwrap_or_else(|| word.infinitive_form()),
+                    forms,
+                });
+            }
+        }
+        if transformations.is_empty() {
+            return Err(InflectError::NoSuchForm {
+                word: form.to_string(),
+                features: target.to_ud(),
+            });
+        }
+        Ok(transformations)
+    }
+
+    /// Number of verbs in each weak root class.
+    pub fn gizra_counts(&self) -> HashMap<String, usize> {
+        let mut counts = HashMap::new();
+        for word in self.data.values().filter(|w| w.is_verb()) {
+            for gizra in &word.gizrot {
+                *counts.entry(gizra.clone()).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    /// Matches a regular expression against every normalized form in the index. The pattern is
+    /// not anchored, use `^` and `$` to match whole forms.
+    pub fn search_regex(
+        &self,
+        pattern: &str,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>, regex::Error> {
+        let re = RegexBuilder::new(normalize(pattern).as_str())
+            .size_limit(MAX_REGEX_SIZE)
+            .build()?;
+        let mut keys: Vec<&String> = self.index.keys().filter(|k| re.is_match(k)).collect();
+        keys.sort();
+        Ok(self.keys_to_results(keys.into_iter().map(|k| k.as_str()), limit))
+    }
 }
 
 #[cfg_attr(feature = "wasm-support", wasm_bindgen)]
@@ -37,7 +245,27 @@ impl WordIndex {
         WordIndex::build(word_datas)
     }
 
-    pub fn build(words: Vec<WordData>) -> WordIndex {
+    pub fn build(mut words: Vec<WordData>) -> WordIndex {
+        // entries deserialized from older data carry no gizrot
+        for word in words.iter_mut().filter(|w| w.gizrot.is_empty()) {
+            word.gizrot = classify_root(&word.root);
+        }
+        for word in words.iter_mut() {
+            mark_irregular(word);
+            if word.infinitives.is_empty() {
+                word.infinitives = infinitive_forms(word);
+            }
+            // the lemma of a verb is its infinitive, other lemmas are indexed as a form
+            if !word.is_verb()
+                && !word
+                    .forms
+                    .iter()
+                    .any(|f| f.form_normalized == word.word_normalized)
+            {
+                let lemma = word.lemma_form();
+                word.forms.push(lemma);
+            }
+        }
         // collect words vector into a hashmap with url_id as key
         let data_index: HashMap<String, WordData> = words
             .iter()
@@ -46,32 +274,37 @@ impl WordIndex {
 
         let mut trie = Trie::new();
 
-        let roots_index: HashMap<String, HashSet<String>> = words
-            .iter()
-            .map(|word| (word.root.clone(), HashSet::from([word.url_id.clone()])))
-            .collect();
+        let mut roots_index: HashMap<String, HashSet<String>> = HashMap::new();
+        for word in &words {
+            let root = normalize_root(&word.root);
+            if root.is_empty() {
+                continue;
+            }
+            roots_index
+                .entry(root)
+                .or_default()
+                .insert(word.url_id.clone());
+        }
 
         let mut translation_index = Trie::new();
         for word in &words {
             let translations = WordIndex::get_translations(word);
             for translation in translations {
-                translation_index.insert(translation, word.url_id.clone());
+                // raw lowercase keys keep partially typed words matching, normalized keys
+                // let inflected and British spelled queries find the base translation
+                let normalized = normalize_en(translation.as_str());
+                translation_index.insert(translation.to_lowercase(), word.url_id.clone());
+                translation_index.insert(normalized, word.url_id.clone());
             }
         }
 
         let mut hebrew_index: HashMap<String, HashSet<String>> = HashMap::new();
         for word in &words {
-            if hebrew_index.contains_key(&word.word_normalized) {
-                // println!("Duplicate form: {}", word.word_normalized);
+            for infinitive in &word.infinitives {
                 hebrew_index
-                    .get_mut(&word.word_normalized)
-                    .unwrap()
+                    .entry(infinitive.form_normalized.clone())
+                    .or_default()
                     .insert(word.url_id.clone());
-            } else {
-                hebrew_index.insert(
-                    word.word_normalized.clone(),
-                    HashSet::from([word.url_id.clone()]),
-                );
             }
         }
         for word in &words {
@@ -121,12 +354,24 @@ impl WordIndex {
                 trie.insert(form.clone(), url_id.clone());
             }
         }
+        let postings = Postings::build(&words);
+        let suffix_index = SuffixIndex::new(hebrew_index.keys());
+        let mut variant_index: HashMap<String, HashSet<String>> = HashMap::new();
+        for form in hebrew_index.keys() {
+            variant_index
+                .entry(defective_spelling(form))
+                .or_default()
+                .insert(form.clone());
+        }
         WordIndex {
             data: data_index,
             index: hebrew_index,
             prefix_tree: trie,
             prefix_tree_en: translation_index,
             roots_index,
+            postings,
+            suffix_index,
+            variant_index,
         }
     }
 
@@ -158,14 +403,69 @@ impl WordIndex {
             .collect()
     }
 
+    /// Conjugation tables of the verb with the given url_id.
+    pub fn conjugation(&self, url_id: &str) -> Option<Conjugation> {
+        self.data
+            .get(url_id)
+            .filter(|word| word.is_verb())
+            .map(|word| word.conjugation())
+    }
+
+    /// Fallback for words `get` does not know: guesses of their root and binyan from the
+    /// regular paradigms, best first, linked to the dataset verbs sharing the root.
+    pub fn analyze_unknown(&self, word: &str) -> Vec<RootGuess> {
+        guess_roots(word, |root| {
+            let mut url_ids: Vec<String> = self
+                .roots_index
+                .get(&normalize_root(root))
+                .into_iter()
+                .flatten()
+                .filter(|id| self.data.get(*id).is_some_and(|word| word.is_verb()))
+                .cloned()
+                .collect();
+            url_ids.sort();
+            url_ids
+        })
+    }
+
+    /// Words with at least one form deviating from the regular paradigm, ordered by url_id.
+    /// Weak roots are not compared, see `WordData::is_irregular`.
+    pub fn irregular_verbs(&self) -> Vec<WordData> {
+        let mut words: Vec<WordData> = self
+            .data
+            .values()
+            .filter(|word| word.is_irregular())
+            .cloned()
+            .collect();
+        words.sort_by(|a, b| a.url_id.cmp(&b.url_id));
+        words
+    }
+
+    /// Words built on a root, separators and final letters are ignored: "מ - ל - ך" and
+    /// "מלכ" are the same root.
     pub fn get_by_root(&self, root: &str) -> Vec<WordData> {
-        let val = self.roots_index.get(root);
+        let val = self.roots_index.get(&normalize_root(root));
         match val {
             Some(v) => self.collect_word_data_by_ids(v),
             None => vec![],
         }
     }
 
+    /// Other words built on the root of the word with the given url_id, e.g. the verb an
+    /// adjective comes from. Words without a root have none.
+    pub fn related_words(&self, url_id: &str) -> Vec<WordData> {
+        let Some(word) = self.data.get(url_id) else {
+            return vec![];
+        };
+        let mut related: Vec<WordData> = self
+            .get_by_root(&word.root)
+            .into_iter()
+            .filter(|w| w.url_id != url_id)
+            .collect();
+        related.sort_by(|a, b| a.url_id.cmp(&b.url_id));
+        related
+    }
+
     pub fn get(&self, word: &str) -> Vec<SearchResult> {
         let word_norm = normalize(word);
         let val = self.index.get(word_norm.as_str());
@@ -182,6 +482,36 @@ impl WordIndex {
         }
     }
 
+    /// Splits a sentence or paragraph into words and looks every word up with `get`.
+    pub fn analyze_text(&self, text: &str) -> Vec<AnalyzedToken> {
+        tokenize(text)
+            .iter()
+            .map(|token| {
+                let analyses = self.get(token.text);
+                AnalyzedToken {
+                    token: token.text.to_string(),
+                    byte_start: token.byte_start,
+                    byte_end: token.byte_end(),
+                    char_start: token.char_start,
+                    char_end: token.char_end(),
+                    known: !analyses.is_empty(),
+                    analyses,
+                }
+            })
+            .collect()
+    }
+
+    /// Interlinear gloss of a text: lemma, transcription, meaning and grammatical tag of
+    /// every reading of every word. Render it with `Gloss::to_text` or `Gloss::to_html`.
+    pub fn gloss(&self, text: &str) -> Gloss {
+        build_gloss(text, |token| self.get(token))
+    }
+
+    /// Adds niqqud to unpointed text using `form_vowelled` of the recognised forms.
+    pub fn vocalize(&self, text: &str) -> Vocalization {
+        vocalize(text, |token| self.get(token))
+    }
+
     pub fn suggest(&self, prefix: &str, limit: usize) -> Vec<SearchResult> {
         let prefix_norm = normalize(prefix).trim_start_matches("to ").to_string();
         let mut suggestions = self.suggest_hebrew(prefix_norm.as_str(), limit);
@@ -208,8 +538,20 @@ impl WordIndex {
     }
 
     pub fn suggest_by_translation(&self, prefix_norm: &str, limit: usize) -> Vec<SearchResult> {
-        // let prefix_norm = normalize(prefix);
-        let ids = self.prefix_tree_en.find(prefix_norm, limit);
+        let prefix_en = normalize_en(prefix_norm);
+        let mut ids = self.prefix_tree_en.find(prefix_en.as_str(), limit);
+        let prefix_lower = prefix_norm.trim().to_lowercase();
+        let prefix_lower = prefix_lower.trim_start_matches("to ");
+        if ids.len() < limit && prefix_lower != prefix_en {
+            for id in self.prefix_tree_en.find(prefix_lower, limit) {
+                if ids.len() >= limit {
+                    break;
+                }
+                if !ids.contains(&id) {
+                    ids.push(id);
+                }
+            }
+        }
         let word_datas: Vec<WordData> = ids
             .iter()
             .map(|id| self.data.get(id).unwrap().clone())
@@ -223,6 +565,148 @@ impl WordIndex {
             .collect()
     }
 
+    /// Finds forms matching a wildcard pattern: `?` stands for one letter and `*` for any
+    /// run of letters, e.g. "ה?ת??" or "*תם".
+    pub fn search_wildcard(&self, pattern: &str, limit: usize) -> Vec<SearchResult> {
+        let mut keys = self
+            .prefix_tree
+            .find_wildcard(normalize(pattern).as_str(), limit);
+        keys.sort();
+        self.keys_to_results(keys.iter().map(|k| k.as_str()), limit)
+    }
+
+    /// Finds forms built on a mishkal / binyan template such as "הִתְקַטֵּל" or "מ1ו23", where
+    /// ק-ט-ל, פ-ע-ל or the digits 1-4 stand for the root letters. A vowelled template is
+    /// matched against `form_vowelled`, an unvowelled one against `form_normalized`. Only
+    /// forms whose bound letters are the word's own root are returned.
+    pub fn search_template(&self, template: &str, limit: usize) -> Vec<TemplateMatch> {
+        let Some(template) = Template::compile(template) else {
+            return vec![];
+        };
+        let mut words: Vec<&WordData> = self.data.values().collect();
+        words.sort_by(|a, b| a.url_id.cmp(&b.url_id));
+        let mut results = Vec::new();
+        for word in words {
+            if results.len() >= limit {
+                break;
+            }
+            let root: Vec<char> = normalize_root(&word.root).chars().collect();
+            let spelling = |normalized: &str, vowelled: Option<&str>| {
+                if template.vowelled {
+                    vowelled.map(prepare_vowelled)
+                } else {
+                    Some(normalized.to_string())
+                }
+            };
+            let mut root_letters: Option<Vec<char>> = None;
+            let mut matches_root = |text: Option<String>| {
+                let bound = text.and_then(|t| template.bind(&t));
+                let matches = bound.as_ref().is_some_and(|bound| *bound == root);
+                if matches && root_letters.is_none() {
+                    root_letters = bound;
+                }
+                matches
+            };
+            let mut matching_forms = Vec::new();
+            for (i, infinitive) in word.infinitives.iter().enumerate() {
+                if matches_root(spelling(
+                    &infinitive.form_normalized,
+                    infinitive.form_vowelled.as_deref(),
+                )) {
+                    matching_forms.push(MatchedForm { index: i, kind: 0 });
+                }
+            }
+            for (i, form) in word.forms.iter().enumerate() {
+                if matches_root(spelling(
+                    &form.form_normalized,
+                    form.form_vowelled.as_deref(),
+                )) {
+                    matching_forms.push(MatchedForm { index: i, kind: 1 });
+                }
+            }
+            for (i, form) in word.passive.iter().flatten().enumerate() {
+                if matches_root(spelling(
+                    &form.form_normalized,
+                    form.form_vowelled.as_deref(),
+                )) {
+                    matching_forms.push(MatchedForm { index: i, kind: 2 });
+                }
+            }
+            if let Some(root_letters) = root_letters {
+                results.push(TemplateMatch {
+                    word: word.clone(),
+                    matching_forms,
+                    root_letters: root_letters.iter().map(|c| c.to_string()).collect(),
+                });
+            }
+        }
+        results
+    }
+
+    /// Finds forms containing `text` anywhere, e.g. "תכת".
+    pub fn search_contains(&self, text: &str, limit: usize) -> Vec<SubstringMatch> {
+        let found = self
+            .suffix_index
+            .find_contains(normalize(text).as_str(), limit);
+        self.substring_matches(found)
+    }
+
+    /// Finds forms ending with `text`, e.g. "תֶּם".
+    pub fn search_suffix(&self, text: &str, limit: usize) -> Vec<SubstringMatch> {
+        let found = self
+            .suffix_index
+            .find_suffix(normalize(text).as_str(), limit);
+        self.substring_matches(found)
+    }
+
+    fn substring_matches(&self, found: Vec<(String, Vec<usize>)>) -> Vec<SubstringMatch> {
+        found
+            .into_iter()
+            .map(|(form, offsets)| {
+                let results = self.keys_to_results(std::iter::once(form.as_str()), usize::MAX);
+                SubstringMatch {
+                    form,
+                    offsets,
+                    results,
+                }
+            })
+            .collect()
+    }
+
+    /// Collects the words behind each index key, marking the forms equal to the key,
+    /// at most `limit` words are returned.
+    fn keys_to_results<'a>(
+        &self,
+        keys: impl Iterator<Item = &'a str>,
+        limit: usize,
+    ) -> Vec<SearchResult> {
+        let mut results: Vec<SearchResult> = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        for key in keys {
+            let Some(ids) = self.index.get(key) else {
+                continue;
+            };
+            let mut ids: Vec<&String> = ids.iter().collect();
+            ids.sort();
+            for id in ids {
+                let word_data = self.data.get(id).unwrap();
+                let matches = WordIndex::matching_forms_inner(word_data, key);
+                match positions.get(id) {
+                    Some(&pos) => results[pos].matching_forms.extend(matches),
+                    None if results.len() < limit => {
+                        positions.insert(id.clone(), results.len());
+                        results.push(SearchResult {
+                            word: word_data.clone(),
+                            matching_forms: matches,
+                        });
+                    }
+                    None => {}
+                }
+            }
+        }
+        results
+    }
+
     pub fn matching_forms(&self, word_id: &str, form_str: &str) -> Vec<MatchedForm> {
         let form_str_norm = normalize(form_str);
         let wd = self.data.get(word_id);
@@ -234,13 +718,122 @@ impl WordIndex {
         }
     }
 
+    /// Evaluates a structured query. Only the forms passing every filter are returned in
+    /// `matching_forms`, words without any such form are left out.
+    pub fn query(&self, query: &Query) -> Vec<SearchResult> {
+        self.form_refs_to_results(self.query_form_refs(query))
+    }
+
+    /// Searches with the text query language, see `parse_query` for the syntax.
+    pub fn search(&self, query: &str) -> Result<Vec<SearchResult>, ParseError> {
+        let expr = parse_query(query)?;
+        Ok(self.form_refs_to_results(self.eval_query_expr(&expr)))
+    }
+
+    fn query_form_refs(&self, query: &Query) -> HashSet<FormRef> {
+        let filtered = self.postings.evaluate(query);
+        let text_matches = query.text.as_ref().map(|text| self.text_form_refs(text));
+        match (filtered, text_matches) {
+            (Some(filtered), Some(text)) => filtered.intersection(&text).cloned().collect(),
+            (Some(filtered), None) => filtered,
+            (None, Some(text)) => text,
+            (None, None) => HashSet::new(),
+        }
+    }
+
+    fn eval_query_expr(&self, expr: &QueryExpr) -> HashSet<FormRef> {
+        match expr {
+            QueryExpr::Term(query) => self.query_form_refs(query),
+            QueryExpr::Or(items) => items
+                .iter()
+                .flat_map(|item| self.eval_query_expr(item))
+                .collect(),
+            QueryExpr::Not(inner) => {
+                let excluded = self.eval_query_expr(inner);
+                self.all_form_refs()
+                    .into_iter()
+                    .filter(|r| !excluded.contains(r))
+                    .collect()
+            }
+            QueryExpr::And(items) => {
+                // negated items are subtracted from the rest instead of being expanded
+                // against every form in the index
+                let (negated, positive): (Vec<&QueryExpr>, Vec<&QueryExpr>) = items
+                    .iter()
+                    .partition(|item| matches!(item, QueryExpr::Not(_)));
+                let mut result = match positive.split_first() {
+                    None => self.all_form_refs(),
+                    Some((first, rest)) => {
+                        rest.iter().fold(self.eval_query_expr(first), |acc, item| {
+                            let set = self.eval_query_expr(item);
+                            acc.into_iter().filter(|r| set.contains(r)).collect()
+                        })
+                    }
+                };
+                for item in negated {
+                    if let QueryExpr::Not(inner) = item {
+                        let excluded = self.eval_query_expr(inner);
+                        result.retain(|r| !excluded.contains(r));
+                    }
+                }
+                result
+            }
+        }
+    }
+
+    fn all_form_refs(&self) -> HashSet<FormRef> {
+        self.data.values().flat_map(all_form_refs).collect()
+    }
+
+    /// Forms matching a Hebrew form exactly, or every form of the words whose translation
+    /// matches when the text is not a known Hebrew form.
+    fn text_form_refs(&self, text: &str) -> HashSet<FormRef> {
+        let text_norm = normalize(text);
+        if let Some(ids) = self.index.get(text_norm.as_str()) {
+            return ids
+                .iter()
+                .flat_map(|id| {
+                    WordIndex::matching_forms_inner(self.data.get(id).unwrap(), &text_norm)
+                        .into_iter()
+                        .map(move |m| FormRef::new(id, m.kind, m.index))
+                })
+                .collect();
+        }
+        self.prefix_tree_en
+            .find_exact(normalize_en(text).as_str())
+            .iter()
+            .flat_map(|id| all_form_refs(self.data.get(id).unwrap()))
+            .collect()
+    }
+
+    fn form_refs_to_results(&self, form_refs: HashSet<FormRef>) -> Vec<SearchResult> {
+        let mut by_word: HashMap<String, Vec<FormRef>> = HashMap::new();
+        for form_ref in form_refs {
+            by_word
+                .entry(form_ref.url_id.clone())
+                .or_default()
+                .push(form_ref);
+        }
+        let mut results: Vec<SearchResult> = by_word
+            .into_iter()
+            .map(|(url_id, mut refs)| {
+                refs.sort();
+                SearchResult {
+                    word: self.data.get(&url_id).unwrap().clone(),
+                    matching_forms: refs.iter().map(FormRef::matched_form).collect(),
+                }
+            })
+            .collect();
+        results.sort_by(|a, b| a.word.url_id.cmp(&b.word.url_id));
+        results
+    }
+
     fn matching_forms_inner(word_data: &WordData, form_str_norm: &str) -> Vec<MatchedForm> {
         let mut matches: Vec<MatchedForm> = Vec::new();
-        if word_data.word_normalized == form_str_norm {
-            // infinitive form is not in the list of forms
-            // usize can't be negative, so we will use word_data.forms.len() as an indication
-            // that it matches the infinitive form
-            matches.push(MatchedForm { index: 0, kind: 0 });
+        for (i, infinitive) in word_data.infinitives.iter().enumerate() {
+            if infinitive.form_normalized == form_str_norm {
+                matches.push(MatchedForm { index: i, kind: 0 });
+            }
         }
         for (i, form) in word_data.forms.iter().enumerate() {
             if form.form_normalized == form_str_norm {
@@ -329,4 +922,187 @@ mod tests {
         // assert_eq!(vec.len() == 1, true);
         // assert_eq!(vec[0].word.passive_binyan.as_deref(), Some("HUF'AL"));
     }
+    #[test]
+    fn suggest_by_translation_folds_english() {
+        let index = crate::test_data::test_index();
+        for query in ["writing", "wrote", "To Write", "organising", "arranged"] {
+            let vec = index.suggest(query, 15);
+            assert_eq!(vec.len(), 1, "no results for '{}'", query);
+        }
+        assert_eq!(index.suggest("wrote", 15)[0].word.url_id, "927-lichtov");
+        assert_eq!(index.suggest("organise", 15)[0].word.url_id, "1420-lesader");
+        assert_eq!(index.suggest("wri", 15)[0].word.url_id, "927-lichtov");
+    }
+
+    #[test]
+    fn search_wildcard_and_regex() {
+        let index = crate::test_data::test_index();
+        let vec = index.search_wildcard("ת?ת??", 15);
+        assert_eq!(vec.len(), 1);
+        assert_eq!(vec[0].word.url_id, "927-lichtov");
+        // תכתבי, תכתבו and תכתוב as both 2nd masculine and 3rd feminine
+        assert_eq!(vec[0].matching_forms.len(), 4);
+
+        let vec = index.search_wildcard("*תם", 15);
+        assert_eq!(vec.len(), 2);
+        assert_eq!(index.search_wildcard("*", 1).len(), 1);
+
+        let vec = index.search_regex("^מ.ו.ר", 15).unwrap();
+        assert_eq!(vec.len(), 1);
+        assert!(vec[0].matching_forms.iter().all(|m| m.kind == 2));
+        assert!(index.search_regex("(", 15).is_err());
+    }
+
+    #[test]
+    fn search_contains_and_suffix() {
+        let index = crate::test_data::test_index();
+        let vec = index.search_suffix("תֶּם", 15);
+        let forms: Vec<&str> = vec.iter().map(|m| m.form.as_str()).collect();
+        assert_eq!(forms, vec!["כתבתם", "סודרתם", "סידרתם"]);
+        assert_eq!(vec[0].offsets, vec![3]);
+        assert_eq!(vec[0].results[0].matching_forms.len(), 1);
+
+        let vec = index.search_contains("תכת", 15);
+        assert_eq!(vec.len(), 4);
+        assert!(vec.iter().all(|m| m.offsets == vec![0]));
+    }
+
+    #[test]
+    fn roots_with_final_letters_are_linked() {
+        let mut word = crate::word_dto::WordData::conjugate("מ-ל-כ", "PA'AL").unwrap();
+        word.url_id = "1000-limloch".to_string();
+        word.root = "מ - ל - ך".to_string();
+        word.synthetic = false;
+        let index = WordIndex::build(vec![word]);
+        assert_eq!(index.get_by_root("מלכ").len(), 1);
+        assert_eq!(index.get_by_root("מ-ל-ך").len(), 1);
+        let guesses = index.analyze_unknown("המלכנו");
+        assert_eq!(guesses[0].root, "מ - ל - כ");
+        assert_eq!(guesses[0].url_ids, vec!["1000-limloch"]);
+    }
+
+    #[test]
+    fn get_by_root_returns_every_word_of_the_root() {
+        let mut other = crate::test_data::lichtov();
+        other.url_id = "928-lehichatev".to_string();
+        let index = WordIndex::build(vec![crate::test_data::lichtov(), other]);
+        let mut url_ids: Vec<String> = index
+            .get_by_root("כ - ת - ב")
+            .into_iter()
+            .map(|w| w.url_id)
+            .collect();
+        url_ids.sort();
+        assert_eq!(url_ids, vec!["927-lichtov", "928-lehichatev"]);
+    }
+
+    #[test]
+    fn rootless_words_are_not_related() {
+        let mut other = crate::test_data::et();
+        other.url_id = "3056-el".to_string();
+        let index = WordIndex::build(vec![crate::test_data::et(), other]);
+        assert!(index.get_by_root("").is_empty());
+        assert!(index.related_words("3055-et").is_empty());
+        assert!(index.get("את")[0].word.gizrot.is_empty());
+        assert!(index.search("gizra:regular pos:prep").unwrap().is_empty());
+    }
+
+    #[test]
+    fn analyze_text() {
+        let index = crate::test_data::test_index();
+        let tokens = index.analyze_text("אתמול כתבתי מכתב, ואז סידרתי אותו.");
+        let words: Vec<(&str, bool)> = tokens.iter().map(|t| (t.token.as_str(), t.known)).collect();
+        assert_eq!(
+            words,
+            vec![
+                ("אתמול", false),
+                ("כתבתי", true),
+                ("מכתב", false),
+                ("ואז", false),
+                ("סידרתי", true),
+                ("אותו", false)
+            ]
+        );
+        assert_eq!(tokens[1].char_start, 6);
+        assert_eq!(tokens[1].char_end, 11);
+        assert_eq!(tokens[1].byte_start, "אתמול ".len());
+        assert_eq!(tokens[4].analyses[0].word.url_id, "1420-lesader");
+    }
+
+    #[test]
+    fn get_noun_forms() {
+        let index = crate::test_data::test_index();
+        let results = index.get("ספריו");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].word.word, "ספר");
+        assert_eq!(results[0].word.part_of_speech, "noun");
+        let matched = &results[0].word.forms[results[0].matching_forms[0].index];
+        assert_eq!(matched.number, "plural");
+        assert_eq!(matched.possessor.as_ref().unwrap().person, "3rd");
+        // construct plural and "my book"
+        assert_eq!(index.get("ספרי")[0].matching_forms.len(), 2);
+        assert!(index.conjugation("6046-sefer").is_none());
+        assert!(index.get("לספר").is_empty());
+    }
+
+    #[test]
+    fn adjective_entries() {
+        let index = crate::test_data::test_index();
+        let results = index.get("סדירות");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].word.part_of_speech, "adjective");
+        // absolute and construct feminine plural
+        assert_eq!(results[0].matching_forms.len(), 2);
+        let suggested = index.suggest("סדירי", 5);
+        assert_eq!(suggested[0].word.url_id, "4893-sadir");
+        let suggested = index.suggest("orderl", 5);
+        assert_eq!(suggested[0].word.url_id, "4893-sadir");
+        let related: Vec<String> = index
+            .related_words("4893-sadir")
+            .into_iter()
+            .map(|w| w.url_id)
+            .collect();
+        assert_eq!(related, vec!["1420-lesader"]);
+        assert!(index.related_words("unknown").is_empty());
+    }
+
+    #[test]
+    fn conjugation_of_verbs_only() {
+        let index = crate::test_data::test_index();
+        let conjugation = index.conjugation("1420-lesader").unwrap();
+        assert!(conjugation.passive.is_some());
+        assert!(index.conjugation("6046-sefer").is_none());
+        assert!(index.conjugation("unknown").is_none());
+    }
+
+    #[test]
+    fn inflected_prepositions() {
+        let index = WordIndex::build(vec![crate::test_data::et(), crate::test_data::lichtov()]);
+        let results = index.get("אותך");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].word.word, "את");
+        let cells: Vec<(&str, &str, &str)> = results[0]
+            .matching_forms
+            .iter()
+            .map(|m| {
+                let form = &results[0].word.forms[m.index];
+                (
+                    form.person.as_str(),
+                    form.number.as_str(),
+                    form.gender.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(cells.len(), 2);
+        assert!(cells.contains(&("2nd", "singular", "f")));
+        let results = index.get("את");
+        assert_eq!(results[0].matching_forms.len(), 1);
+        let lemma = &results[0].word.forms[results[0].matching_forms[0].index];
+        assert_eq!((lemma.form.as_str(), lemma.person.as_str()), ("את", ""));
+        assert_eq!(index.lemmatize("את")[0].1, "3055-et");
+        assert!(index.analyze_text("את")[0].known);
+        let results = index.search("pos:prep person:3 number:pl").unwrap();
+        assert_eq!(results[0].matching_forms.len(), 2);
+        let tokens = index.analyze_text("כתבתי אותם");
+        assert_eq!(tokens[1].analyses[0].word.url_id, "3055-et");
+    }
 }